@@ -0,0 +1,102 @@
+use std::fmt::Display;
+use std::num::TryFromIntError;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// The name of the answer type as used in the machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Number(value)
+    }
+}
+
+/// Fails for values above `i128::MAX`, which a number answer cannot hold.
+impl TryFrom<u128> for Answer {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        Ok(Answer::Number(value.try_into()?))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn answers_display_their_value() {
+        assert_eq!(Answer::from(42).to_string(), "42");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::from(u64::MAX as i128 * 3).to_string(), "55340232221128654845");
+    }
+
+    #[test]
+    fn wide_answers_must_fit_in_127_bits() {
+        assert_eq!(Answer::try_from(u64::MAX as u128 * 3), Ok(Answer::Number(u64::MAX as i128 * 3)));
+        assert!(Answer::try_from(u128::MAX).is_err());
+    }
+
+    #[test]
+    fn answers_report_their_kind() {
+        assert_eq!(Answer::from(42).kind(), "number");
+        assert_eq!(Answer::from("CMZ").kind(), "text");
+    }
+}
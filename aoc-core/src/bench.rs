@@ -0,0 +1,221 @@
+use crate::json::JsonObject;
+use crate::{ParseError, Part, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub warmup: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 100,
+            warmup: 10,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: u32,
+    pub options: BenchOptions,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl Stats {
+    /// Summarizes a set of timings. The standard deviation is the sample
+    /// standard deviation, so a single sample has a deviation of zero.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let nanos: Vec<f64> = sorted
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        let variance = if count > 1 {
+            nanos
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+
+    pub fn to_csv_fields(&self) -> [String; 4] {
+        [self.min, self.median, self.mean, self.std_dev].map(|value| value.as_nanos().to_string())
+    }
+
+    pub fn to_json(&self, phase: &str) -> JsonObject {
+        JsonObject::new()
+            .string("phase", phase)
+            .number("min_ns", self.min.as_nanos())
+            .number("median_ns", self.median.as_nanos())
+            .number("mean_ns", self.mean.as_nanos())
+            .number("std_dev_ns", self.std_dev.as_nanos())
+    }
+}
+
+impl BenchReport {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part_one", &self.part_one),
+            ("part_two", &self.part_two),
+        ]
+    }
+
+    pub const CSV_HEADER: &'static str =
+        "day,phase,iterations,warmup,min_ns,median_ns,mean_ns,std_dev_ns";
+
+    /// One CSV row per phase, matching [`BenchReport::CSV_HEADER`].
+    pub fn to_csv(&self) -> Vec<String> {
+        self.phases()
+            .iter()
+            .map(|(phase, stats)| {
+                let fields = [
+                    self.day.to_string(),
+                    phase.to_string(),
+                    self.options.iterations.to_string(),
+                    self.options.warmup.to_string(),
+                ];
+
+                crate::csv::row(fields.into_iter().chain(stats.to_csv_fields()))
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> JsonObject {
+        let phases = self
+            .phases()
+            .map(|(phase, stats)| stats.to_json(phase).to_string());
+
+        JsonObject::new()
+            .number("day", self.day)
+            .number("iterations", self.options.iterations)
+            .number("warmup", self.options.warmup)
+            .raw("phases", crate::json::array(phases))
+    }
+}
+
+fn measure<T, F: FnMut() -> T>(options: &BenchOptions, mut run: F) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(run());
+    }
+
+    let samples: Vec<Duration> = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+/// Times parsing and both parts separately. The parts are timed against a
+/// single parsed input, so their numbers do not include parsing.
+pub fn bench<S: Solution>(
+    input_data: &str,
+    options: &BenchOptions,
+) -> Result<BenchReport, ParseError> {
+    let input = S::parse(input_data)?;
+
+    for part in Part::ALL {
+        S::check(&input, part)?;
+    }
+
+    let parse = measure(options, || S::parse(black_box(input_data)));
+    let part_one = measure(options, || S::part_one(black_box(&input)));
+    let part_two = measure(options, || S::part_two(black_box(&input)));
+
+    Ok(BenchReport {
+        day: S::DAY,
+        options: *options,
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos < 1_000.0 {
+        format!("{:.0} ns", nanos)
+    } else if nanos < 1_000_000.0 {
+        format!("{:.2} µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.2} ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2} s", nanos / 1_000_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|value| Duration::from_millis(*value))
+            .collect()
+    }
+
+    #[test]
+    fn from_samples_computes_summary_statistics() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 8, 6]));
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.std_dev.as_micros(), 2581);
+    }
+
+    #[test]
+    fn from_samples_handles_a_single_sample() {
+        let stats = Stats::from_samples(&millis(&[3]));
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+
+    #[test]
+    fn format_duration_picks_a_readable_unit() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512 ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50 ms");
+    }
+}
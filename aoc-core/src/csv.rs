@@ -0,0 +1,35 @@
+/// Quotes a CSV field when it contains a separator, a quote or a line break.
+pub fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn row<I: IntoIterator<Item = String>>(fields: I) -> String {
+    fields
+        .into_iter()
+        .map(|value| field(&value))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_quotes_only_when_needed() {
+        assert_eq!(field("CMZ"), "CMZ");
+        assert_eq!(field("a,b"), "\"a,b\"");
+        assert_eq!(field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn row_joins_fields() {
+        let fields = vec!["1".to_string(), "x,y".to_string()];
+
+        assert_eq!(row(fields), "1,\"x,y\"");
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+/// A problem in the puzzle input. Lines and columns are 1-based and columns
+/// are counted in characters, so they match what an editor shows.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Creates an error for `text`, which must be a slice of `line`. The
+    /// column is derived from where the slice starts within the line.
+    pub fn in_line(line_number: usize, line: &str, text: &str, expected: &str) -> ParseError {
+        let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+        let column = if offset <= line.len() && line.is_char_boundary(offset) {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        ParseError::new(line_number, column, text, expected)
+    }
+
+    pub fn with_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    /// Renders the error together with the offending line of `input_data`,
+    /// pointing at the text that could not be parsed.
+    pub fn render(&self, input_data: &str) -> String {
+        let line_label = self.line.to_string();
+        let gutter = " ".repeat(line_label.len());
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file, self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        };
+
+        let mut output = format!("error: {}\n{}--> {}\n", self.message(), gutter, location);
+
+        if let Some(source_line) = input_data.lines().nth(self.line.saturating_sub(1)) {
+            let padding = " ".repeat(self.column.saturating_sub(1));
+            let marker = "^".repeat(self.text.chars().count().max(1));
+
+            output.push_str(&format!("{} |\n", gutter));
+            output.push_str(&format!("{} | {}\n", line_label, source_line));
+            output.push_str(&format!("{} | {}{}\n", gutter, padding, marker));
+        }
+
+        output
+    }
+
+    fn message(&self) -> String {
+        if self.text.is_empty() {
+            format!("expected {}, found nothing", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.text)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+
+        write!(f, "{}:{}: {}", self.line, self.column, self.message())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn in_line_derives_the_column_from_the_slice() {
+        let line = "35-7x,35-82";
+        let error = ParseError::in_line(3, line, &line[3..5], "a number");
+
+        assert_eq!(error.column, 4);
+        assert_eq!(error.text, "7x");
+    }
+
+    #[test]
+    fn display_includes_the_location() {
+        let error = ParseError::new(2, 5, "abc", "a number").with_file("input.txt");
+
+        assert_eq!(error.to_string(), "input.txt:2:5: expected a number, found `abc`");
+    }
+
+    #[test]
+    fn render_points_at_the_offending_text() {
+        let input_data = "1-2,3-4\n5-x,7-8\n";
+        let error = ParseError::new(2, 3, "x", "a number").with_file("input.txt");
+
+        let expected = "error: expected a number, found `x`
+ --> input.txt:2:3
+  |
+2 | 5-x,7-8
+  |   ^
+";
+
+        assert_eq!(error.render(input_data), expected);
+    }
+}
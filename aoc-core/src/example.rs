@@ -0,0 +1,57 @@
+use crate::{Answer, ParseError, Part, Solution};
+
+/// The worked example from a puzzle description together with the answers
+/// the puzzle text gives for it.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: &'static str,
+    pub part_two: &'static str,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> &'static str {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+pub fn solve_example<S: Solution>(part: Part) -> Result<Answer, ParseError> {
+    let input = S::parse(S::EXAMPLE.input)?;
+    S::check(&input, part)?;
+
+    let answer = match part {
+        Part::One => S::part_one(&input),
+        Part::Two => S::part_two(&input),
+    };
+
+    Ok(answer)
+}
+
+/// Generates `example_part_one` and `example_part_two` tests that solve the
+/// embedded example of a [`Solution`] and compare against its answers.
+/// Attributes before the type, such as `#[ignore]`, go on both tests.
+#[macro_export]
+macro_rules! example_tests {
+    ($(#[$attribute:meta])* $solution:ty) => {
+        #[test]
+        $(#[$attribute])*
+        fn example_part_one() {
+            let expected = <$solution as $crate::Solution>::EXAMPLE.part_one;
+            let answer = $crate::solve_example::<$solution>($crate::Part::One).unwrap();
+
+            assert_eq!(answer.to_string(), expected);
+        }
+
+        #[test]
+        $(#[$attribute])*
+        fn example_part_two() {
+            let expected = <$solution as $crate::Solution>::EXAMPLE.part_two;
+            let answer = $crate::solve_example::<$solution>($crate::Part::Two).unwrap();
+
+            assert_eq!(answer.to_string(), expected);
+        }
+    };
+}
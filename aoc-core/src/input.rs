@@ -0,0 +1,234 @@
+use crate::Solution;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const INPUT_FLAG: &str = "--input";
+pub const WORKSPACE_VARIABLE: &str = "AOC_WORKSPACE_DIR";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingValue,
+    Unreadable { source: InputSource, error: io::Error },
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingValue => write!(f, "Missing value for {}", INPUT_FLAG),
+            InputError::Unreadable { source, error } => {
+                write!(f, "Unable to read {}: {}", source, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The root of the workspace. It can be overridden with `AOC_WORKSPACE_DIR`
+/// for binaries that are moved away from the checkout they were built in.
+pub fn workspace_dir() -> PathBuf {
+    match env::var_os(WORKSPACE_VARIABLE) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf(),
+    }
+}
+
+pub fn day_dir(day: u32) -> PathBuf {
+    workspace_dir().join(format!("day{:02}", day))
+}
+
+pub fn default_input_path(day: u32) -> PathBuf {
+    day_dir(day).join("data").join("input.txt")
+}
+
+impl InputSource {
+    /// Maps the value of `--input` to a source: `-` is stdin, any other value
+    /// is a path and no value at all is the day's own `data/input.txt`.
+    pub fn for_day(day: u32, input: Option<&str>) -> InputSource {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(default_input_path(day)),
+        }
+    }
+
+    pub fn from_args(day: u32, args: &[String]) -> Result<InputSource, InputError> {
+        let input = match args.iter().position(|arg| arg == INPUT_FLAG) {
+            Some(index) => Some(args.get(index + 1).ok_or(InputError::MissingValue)?),
+            None => None,
+        };
+
+        Ok(InputSource::for_day(day, input.map(|value| value.as_str())))
+    }
+
+    /// Opens the source for reading line by line, without loading all of it
+    /// in memory.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        let result: io::Result<Box<dyn BufRead>> = match self {
+            InputSource::File(path) => fs::File::open(path).map(|file| Box::new(BufReader::new(file)) as _),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        };
+
+        result.map_err(|error| InputError::Unreadable {
+            source: self.clone(),
+            error,
+        })
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input_data = String::new();
+                io::stdin().read_to_string(&mut input_data).map(|_| input_data)
+            }
+        };
+
+        result.map_err(|error| InputError::Unreadable {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+pub fn load(day: u32, args: &[String]) -> Result<String, InputError> {
+    InputSource::from_args(day, args)?.read()
+}
+
+/// Reads the input selected on the command line, exiting with a readable
+/// message when that fails.
+pub fn read_or_exit(day: u32) -> (InputSource, String) {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let source = InputSource::from_args(day, &args).unwrap_or_else(|err| exit_with(&err));
+    let input_data = source.read().unwrap_or_else(|err| exit_with(&err));
+
+    (source, input_data)
+}
+
+/// Convenience for the day binaries: reads and parses the input selected on
+/// the command line and exits with a readable diagnostic when that fails.
+pub fn parse_or_exit<S: Solution>() -> S::Input {
+    let (source, input_data) = read_or_exit(S::DAY);
+
+    S::parse(&input_data).unwrap_or_else(|err| {
+        let err = err.with_file(&source.to_string());
+        exit_with(&err.render(&input_data))
+    })
+}
+
+/// Looks up the value of a day specific flag such as `--top 5`.
+pub fn flag_value<'a>(args: &'a [String], flag: &str) -> Result<Option<&'a str>, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(index) => match args.get(index + 1) {
+            Some(value) => Ok(Some(value.as_str())),
+            None => Err(format!("Missing value for {}", flag)),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Reads and converts the value of a day specific flag from the command line,
+/// exiting with a message when the value is missing or invalid.
+pub fn flag_or_exit<T: FromStr>(flag: &str) -> Option<T> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let value = flag_value(&args, flag).unwrap_or_else(|err| exit_with(&err))?;
+
+    let parsed = value
+        .parse()
+        .unwrap_or_else(|_| exit_with(&format!("Invalid value '{}' for {}", value, flag)));
+
+    Some(parsed)
+}
+
+/// Prints the message to stderr and exits with status 1. Trailing newlines,
+/// such as the one a rendered [`crate::ParseError`] ends with, are dropped.
+pub fn exit_with(message: &dyn Display) -> ! {
+    eprintln!("{}", message.to_string().trim_end());
+    std::process::exit(1);
+}
+
+/// Splits a command line written as one string into its arguments, for
+/// tests of argument parsing.
+pub fn to_args(text: &str) -> Vec<String> {
+    text.split_whitespace().map(|arg| arg.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_input_path_points_into_the_day_folder() {
+        let path = default_input_path(1);
+
+        assert!(path.ends_with("day01/data/input.txt"));
+        assert!(path.is_absolute());
+    }
+
+    #[test]
+    fn for_day_maps_input_values() {
+        assert_eq!(InputSource::for_day(3, Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::for_day(3, Some("other.txt")),
+            InputSource::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            InputSource::for_day(3, None),
+            InputSource::File(default_input_path(3))
+        );
+    }
+
+    #[test]
+    fn from_args_reads_the_input_flag() {
+        let source = InputSource::from_args(2, &to_args("--part 1 --input big.txt")).unwrap();
+
+        assert_eq!(source, InputSource::File(PathBuf::from("big.txt")));
+    }
+
+    #[test]
+    fn from_args_requires_a_value() {
+        let result = InputSource::from_args(2, &to_args("--input"));
+
+        assert!(matches!(result, Err(InputError::MissingValue)));
+    }
+
+    #[test]
+    fn flag_value_finds_day_specific_flags() {
+        let args = to_args("--input big.txt --top 5");
+
+        assert_eq!(flag_value(&args, "--top"), Ok(Some("5")));
+        assert_eq!(flag_value(&args, "--report"), Ok(None));
+        assert!(flag_value(&to_args("--top"), "--top").is_err());
+    }
+
+    #[test]
+    fn read_reports_the_missing_file() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let message = source.read().unwrap_err().to_string();
+
+        assert!(message.starts_with("Unable to read does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+/// A minimal JSON object writer for the machine-readable outputs. Values are
+/// added in order and nested objects or arrays can be passed in as raw JSON.
+#[derive(Debug, Default, Clone)]
+pub struct JsonObject {
+    fields: Vec<(String, String)>,
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+pub fn array<I: IntoIterator<Item = String>>(items: I) -> String {
+    format!("[{}]", items.into_iter().collect::<Vec<_>>().join(","))
+}
+
+impl JsonObject {
+    pub fn new() -> JsonObject {
+        JsonObject::default()
+    }
+
+    pub fn string(mut self, key: &str, value: &str) -> JsonObject {
+        self.fields.push((key.to_string(), escape(value)));
+        self
+    }
+
+    pub fn number<T: Display>(mut self, key: &str, value: T) -> JsonObject {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn raw(mut self, key: &str, json: String) -> JsonObject {
+        self.fields.push((key.to_string(), json));
+        self
+    }
+}
+
+impl Display for JsonObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(key, value)| format!("{}:{}", escape(key), value))
+            .collect();
+
+        write!(f, "{{{}}}", fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_quotes_special_characters() {
+        assert_eq!(escape("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+    }
+
+    #[test]
+    fn objects_keep_field_order() {
+        let object = JsonObject::new()
+            .number("day", 5)
+            .string("answer", "CMZ")
+            .raw("parts", array(vec!["1".to_string(), "2".to_string()]));
+
+        assert_eq!(
+            object.to_string(),
+            "{\"day\":5,\"answer\":\"CMZ\",\"parts\":[1,2]}"
+        );
+    }
+}
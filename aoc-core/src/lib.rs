@@ -0,0 +1,15 @@
+mod answer;
+pub mod bench;
+pub mod csv;
+mod error;
+mod example;
+pub mod input;
+pub mod json;
+pub mod rng;
+mod solution;
+pub mod verify;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use example::{solve_example, Example};
+pub use solution::{Part, Puzzle, Solution, Solved};
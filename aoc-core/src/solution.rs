@@ -0,0 +1,196 @@
+use crate::bench::{self, BenchOptions, BenchReport};
+use crate::rng::Rng;
+use crate::{Answer, Example, ParseError};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle, along with the time it took to
+/// compute it. Parsing is not included in the elapsed time.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// A puzzle solution for a single day. The input is parsed once and then
+/// shared by both parts of the puzzle.
+pub trait Solution {
+    const DAY: u32;
+    const EXAMPLE: Example;
+
+    type Input;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+
+    /// Checks that the parsed input can be solved for `part`, for inputs that
+    /// only one of the parts has requirements on. Runs before that part is
+    /// solved, so the parts themselves can rely on it.
+    fn check(_input: &Self::Input, _part: Part) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    /// Writes a random but valid puzzle input of roughly `size` records, for
+    /// benchmarks and differential tests. Days without a generator return
+    /// `None`.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe view on a [`Solution`], so the runner can keep every day in a
+/// single list regardless of its input type.
+pub trait Puzzle {
+    fn day(&self) -> u32;
+    fn example(&self) -> Example;
+    fn solve(&self, input_data: &str, parts: &[Part]) -> Result<Vec<Solved>, ParseError>;
+    fn bench(&self, input_data: &str, options: &BenchOptions) -> Result<BenchReport, ParseError>;
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn example(&self) -> Example {
+        S::EXAMPLE
+    }
+
+    fn solve(&self, input_data: &str, parts: &[Part]) -> Result<Vec<Solved>, ParseError> {
+        let input = S::parse(input_data)?;
+
+        for part in parts {
+            S::check(&input, *part)?;
+        }
+
+        let answers = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part_one(&input),
+                    Part::Two => S::part_two(&input),
+                };
+
+                Solved {
+                    part: *part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(answers)
+    }
+
+    fn bench(&self, input_data: &str, options: &BenchOptions) -> Result<BenchReport, ParseError> {
+        bench::bench::<S>(input_data, options)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+        const EXAMPLE: Example = Example {
+            input: "2\n3\n4\n",
+            part_one: "9",
+            part_two: "24",
+        };
+
+        type Input = Vec<i32>;
+
+        fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+            input_data
+                .lines()
+                .enumerate()
+                .map(|(index, line)| {
+                    line.parse()
+                        .map_err(|_| ParseError::in_line(index + 1, line, line, "a number"))
+                })
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Answer {
+            input.iter().sum::<i32>().into()
+        }
+
+        fn part_two(input: &Self::Input) -> Answer {
+            input.iter().product::<i32>().into()
+        }
+
+        fn check(input: &Self::Input, part: Part) -> Result<(), ParseError> {
+            match part {
+                Part::Two if input.is_empty() => {
+                    Err(ParseError::new(1, 1, "", "a number to multiply"))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+
+    crate::example_tests!(Sum);
+
+    #[test]
+    fn puzzle_solves_requested_parts() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let answers = puzzle.solve("2\n3\n4", &[Part::Two]).unwrap();
+
+        assert_eq!(puzzle.day(), 0);
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+        assert_eq!(answers[0].answer, Answer::Number(24));
+    }
+
+    #[test]
+    fn puzzle_reports_parse_errors() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let error = puzzle.solve("2\nthree", &Part::ALL).unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 1, "three", "a number"));
+    }
+
+    #[test]
+    fn puzzle_checks_only_the_requested_parts() {
+        let puzzle: &dyn Puzzle = &Sum;
+
+        assert_eq!(
+            puzzle.solve("", &[Part::One]).unwrap()[0].answer,
+            Answer::Number(0)
+        );
+        assert_eq!(
+            puzzle.solve("", &Part::ALL),
+            Err(ParseError::new(1, 1, "", "a number to multiply"))
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::input::day_dir;
+use crate::{Answer, ParseError, Part};
+use std::path::PathBuf;
+
+const PART_ONE_KEY: &str = "part_one";
+const PART_TWO_KEY: &str = "part_two";
+
+/// The answers that were accepted for a day's puzzle input, as recorded in
+/// `data/answers.txt`. Either part may not have been recorded yet.
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct ExpectedAnswers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Missing,
+}
+
+pub fn answers_path(day: u32) -> PathBuf {
+    day_dir(day).join("data").join("answers.txt")
+}
+
+impl ExpectedAnswers {
+    /// Reads lines like `part_one: 42`. Empty lines and lines starting with
+    /// `#` are ignored.
+    pub fn parse(text: &str) -> Result<ExpectedAnswers, ParseError> {
+        let mut answers = ExpectedAnswers::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let content = line.trim();
+
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = content.split_once(':').ok_or_else(|| {
+                ParseError::in_line(index + 1, line, content, "an answer like `part_one: 42`")
+            })?;
+
+            let answer = Some(value.trim().to_string());
+
+            match key.trim() {
+                PART_ONE_KEY => answers.part_one = answer,
+                PART_TWO_KEY => answers.part_two = answer,
+                _ => {
+                    return Err(ParseError::in_line(index + 1, line, key, "`part_one` or `part_two`"));
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_both_parts() {
+        let answers = ExpectedAnswers::parse("# day 5\npart_one: CMZ\n\npart_two:  MCD \n").unwrap();
+
+        assert_eq!(answers.expected(Part::One), Some("CMZ"));
+        assert_eq!(answers.expected(Part::Two), Some("MCD"));
+    }
+
+    #[test]
+    fn parse_reports_unknown_keys() {
+        let error = ExpectedAnswers::parse("part_one: 1\npart_3: 2\n").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 1, "part_3", "`part_one` or `part_two`"));
+    }
+
+    #[test]
+    fn check_compares_the_rendered_answer() {
+        let answers = ExpectedAnswers::parse("part_one: 24000\n").unwrap();
+
+        assert_eq!(answers.check(Part::One, &Answer::from(24000)), Verdict::Match);
+        assert_eq!(
+            answers.check(Part::One, &Answer::from(41000)),
+            Verdict::Mismatch {
+                expected: "24000".to_string()
+            }
+        );
+        assert_eq!(answers.check(Part::Two, &Answer::from(45000)), Verdict::Missing);
+    }
+}
@@ -0,0 +1,319 @@
+mod planner;
+mod report;
+mod stream;
+
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub use planner::{plan_redistribution, Method, Plan, Snack, EXACT_LIMIT};
+pub use report::{calorie_report, Bucket, CalorieReport, Distribution, Outlier, OUTLIER_Z_SCORE};
+pub use stream::{scan_elves, ElfScan, ScanError};
+
+/// The snacks carried by one elf. `index` is the position of the elf in the
+/// input, starting at zero.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// The calories carried by the elf. [`parse_elves`] rejects elves whose
+    /// total does not fit in 64 bits, so this only panics for elves that were
+    /// built by hand.
+    pub fn total(&self) -> u64 {
+        self.items
+            .iter()
+            .try_fold(0u64, |total, item| total.checked_add(*item))
+            .expect("elf total does not fit in 64 bits")
+    }
+}
+
+/// Reads one line of the input given the running total of the current elf.
+/// Returns `None` for the separator lines between elves.
+pub(crate) fn parse_item(line_number: usize, line: &str, total: u64) -> Result<Option<u64>, ParseError> {
+    let text = line.trim();
+
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    let calories: u64 = text.parse().map_err(|_| {
+        let expected = if text.bytes().all(|b| b.is_ascii_digit()) {
+            "a calorie count that fits in 64 bits"
+        } else {
+            "a calorie count"
+        };
+        ParseError::in_line(line_number, line, text, expected)
+    })?;
+
+    if total.checked_add(calories).is_none() {
+        let expected = "a calorie count that keeps the elf's total within 64 bits";
+        return Err(ParseError::in_line(line_number, line, text, expected));
+    }
+
+    Ok(Some(calories))
+}
+
+/// Reads the elves from the puzzle input. Elves are separated by lines that
+/// are empty or only contain whitespace, line endings may be `\n` or `\r\n`
+/// and the last elf does not need to be followed by a separator.
+pub fn parse_elves(input_data: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = Vec::new();
+    let mut items = Vec::new();
+    let mut total = 0;
+
+    for (index, line) in input_data.lines().enumerate() {
+        match parse_item(index + 1, line, total)? {
+            Some(calories) => {
+                items.push(calories);
+                total += calories;
+            }
+            None if !items.is_empty() => {
+                elves.push(Elf {
+                    index: elves.len(),
+                    items: std::mem::take(&mut items),
+                });
+                total = 0;
+            }
+            None => {}
+        }
+    }
+
+    if !items.is_empty() {
+        elves.push(Elf {
+            index: elves.len(),
+            items,
+        });
+    }
+
+    Ok(elves)
+}
+
+/// One of the heaviest elves as returned by [`top_k`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RankedElf {
+    pub index: usize,
+    pub total: u64,
+}
+
+/// Keeps the `k` heaviest elves seen so far in a min-heap, where "heavier"
+/// means a higher total or, for equal totals, a lower index.
+pub(crate) struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub(crate) fn new(k: usize) -> TopK {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub(crate) fn push(&mut self, index: usize, total: u64) {
+        if self.k == 0 {
+            return;
+        }
+
+        self.heap.push(Reverse((total, Reverse(index))));
+
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    pub(crate) fn into_ranked(self) -> Vec<RankedElf> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| RankedElf { index, total })
+            .collect()
+    }
+}
+
+/// Returns the `k` elves carrying the most calories, heaviest first, in
+/// O(n log k) time. Elves with equal totals keep their input order: the elf
+/// that comes first ranks higher and wins the last place in the selection.
+pub fn top_k(elves: &[Elf], k: usize) -> Vec<RankedElf> {
+    let mut top = TopK::new(k);
+
+    for elf in elves {
+        top.push(elf.index, elf.total());
+    }
+
+    top.into_ranked()
+}
+
+/// Writes the snacks of `elves` elves, each carrying one to ten items.
+pub fn generate_input(rng: &mut Rng, elves: usize) -> String {
+    let groups: Vec<String> = (0..elves.max(1))
+        .map(|_| {
+            let items = rng.between(1, 10);
+            let calories: Vec<String> = (0..items).map(|_| rng.between(1000, 60000).to_string()).collect();
+
+            calories.join("\n")
+        })
+        .collect();
+
+    groups.join("\n\n") + "\n"
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "24000",
+        part_two: "45000",
+    };
+
+    type Input = Vec<Elf>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_elves(input_data)
+    }
+
+    fn part_one(elves: &Self::Input) -> Answer {
+        // Without elves there is nothing to carry.
+        elves.iter().map(Elf::total).max().unwrap_or(0).into()
+    }
+
+    fn part_two(elves: &Self::Input) -> Answer {
+        // Three 64-bit totals always fit in 127 bits.
+        top_k(elves, 3).iter().map(|elf| elf.total as i128).sum::<i128>().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_input, parse_elves, top_k, Day01, Elf, RankedElf};
+    use aoc_core::rng::Rng;
+    use aoc_core::{ParseError, Solution};
+    use std::cmp::Reverse;
+
+    aoc_core::example_tests!(super::Day01);
+
+    #[test]
+    fn parse_elves_reports_invalid_lines() {
+        let result = parse_elves("1000\n2000\n\n30x0\n");
+
+        assert_eq!(result, Err(ParseError::new(4, 1, "30x0", "a calorie count")));
+    }
+
+    #[test]
+    fn parse_elves_reports_overflowing_totals() {
+        let too_big = "18446744073709551616";
+        let half = (u64::MAX / 2 + 1).to_string();
+
+        assert_eq!(
+            parse_elves(&format!("1\n\n{}\n", too_big)),
+            Err(ParseError::new(3, 1, too_big, "a calorie count that fits in 64 bits"))
+        );
+        assert_eq!(
+            parse_elves(&format!("{}\n{}\n", half, half)),
+            Err(ParseError::new(
+                2,
+                1,
+                &half,
+                "a calorie count that keeps the elf's total within 64 bits"
+            ))
+        );
+    }
+
+    #[test]
+    fn part_two_does_not_overflow() {
+        let elves = parse_elves(&vec![u64::MAX.to_string(); 3].join("\n\n")).unwrap();
+
+        assert_eq!(Day01::part_two(&elves).to_string(), "55340232221128654845");
+    }
+
+    #[test]
+    fn empty_input_has_no_calories() {
+        let elves = parse_elves("").unwrap();
+
+        assert_eq!(Day01::part_one(&elves).to_string(), "0");
+        assert_eq!(Day01::part_two(&elves).to_string(), "0");
+    }
+
+    #[test]
+    fn parse_elves_keeps_the_last_elf() {
+        let elves = parse_elves("1000\n2000\n\n3000").unwrap();
+
+        assert_eq!(
+            elves,
+            vec![
+                Elf {
+                    index: 0,
+                    items: vec![1000, 2000]
+                },
+                Elf {
+                    index: 1,
+                    items: vec![3000]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_elves_handles_crlf_and_blank_separators() {
+        let elves = parse_elves("1000\r\n2000\r\n  \r\n\r\n3000\r\n\t\r\n4000 \r\n").unwrap();
+        let totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+
+        assert_eq!(totals, vec![3000, 3000, 4000]);
+        assert_eq!(elves[2].index, 2);
+    }
+
+    #[test]
+    fn top_k_returns_the_heaviest_elves_first() {
+        let elves = parse_elves("100\n\n300\n\n200\n\n50\n250").unwrap();
+
+        assert_eq!(
+            top_k(&elves, 2),
+            vec![RankedElf { index: 1, total: 300 }, RankedElf { index: 3, total: 300 }]
+        );
+        assert_eq!(top_k(&elves, 10).len(), 4);
+        assert!(top_k(&elves, 0).is_empty());
+    }
+
+    #[test]
+    fn top_k_prefers_earlier_elves_on_ties() {
+        let elves = parse_elves("5\n\n7\n\n5\n\n7\n\n5").unwrap();
+        let indices: Vec<usize> = top_k(&elves, 3).iter().map(|elf| elf.index).collect();
+
+        assert_eq!(indices, vec![1, 3, 0]);
+    }
+
+    #[test]
+    fn top_k_matches_a_full_sort() {
+        let elves = parse_elves(&generate_input(&mut Rng::new(12), 500)).unwrap();
+
+        let mut sorted: Vec<RankedElf> = elves
+            .iter()
+            .map(|elf| RankedElf {
+                index: elf.index,
+                total: elf.total(),
+            })
+            .collect();
+        sorted.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+        sorted.truncate(25);
+
+        assert_eq!(top_k(&elves, 25), sorted);
+    }
+
+    #[test]
+    fn generate_input_writes_one_group_per_elf() {
+        let input_data = generate_input(&mut Rng::new(1), 50);
+
+        assert_eq!(parse_elves(&input_data).unwrap().len(), 50);
+        assert_eq!(input_data, generate_input(&mut Rng::new(1), 50));
+    }
+}
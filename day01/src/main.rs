@@ -1,30 +1,113 @@
-use std::fs;
+use aoc_core::input::{self, exit_with, InputSource};
+use aoc_core::Solution;
+use day01::{
+    calorie_report, plan_redistribution, scan_elves, top_k, Day01, Elf, Method, RankedElf,
+    ScanError,
+};
+use std::env;
+
+fn print_top(k: usize, top: &[RankedElf]) {
+    println!("\nTop {} elves:", k);
+
+    for (rank, elf) in top.iter().enumerate() {
+        println!(
+            "{:>4}. elf {} carries {} calories",
+            rank + 1,
+            elf.index,
+            elf.total
+        );
+    }
+}
+
+fn print_plan(elves: &[Elf], carriers: usize) {
+    let plan = plan_redistribution(elves, carriers)
+        .unwrap_or_else(|| exit_with(&"--plan needs at least one elf"));
+
+    let method = match plan.method {
+        Method::Exact => "exact",
+        Method::Heuristic => "heuristic",
+    };
+
+    println!(
+        "\nPlan for {} elves ({}): max load {}, lower bound {}{}",
+        carriers,
+        method,
+        plan.max_load,
+        plan.lower_bound,
+        if plan.is_optimal() { ", optimal" } else { "" }
+    );
+
+    for (carrier, (snacks, load)) in plan.assignment.iter().zip(plan.loads()).enumerate() {
+        println!(
+            "{:>4}. {} calories in {} snacks",
+            carrier,
+            load,
+            snacks.len()
+        );
+    }
+}
+
+/// Answers from a single pass over the input, for inputs too large to load.
+fn stream(top: Option<usize>) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let source = InputSource::from_args(Day01::DAY, &args).unwrap_or_else(|err| exit_with(&err));
+    let reader = source.open().unwrap_or_else(|err| exit_with(&err));
+
+    let k = top.unwrap_or(3).max(3);
+    let scan = scan_elves(reader, k).unwrap_or_else(|err| match err {
+        ScanError::Parse(err) => exit_with(&err.with_file(&source.to_string())),
+        err => exit_with(&err),
+    });
+
+    println!("Elves: {}", scan.elves);
+    println!("Calory count: {}", scan.part_one());
+    println!("Top 3 calory count: {}", scan.part_two());
+
+    if let Some(k) = top {
+        print_top(k, &scan.top[..k.min(scan.top.len())]);
+    }
+}
 
 fn main() {
-    let input_data =  fs::read_to_string("data/input.txt").unwrap();
-    let lines = input_data.lines();
-
-    let mut current_count = 0;
-    let mut calory_count = Vec::new();
-
-    for line in lines {
-        if line.is_empty() {
-            calory_count.push(current_count);
-            current_count = 0;
-        } else {
-            let calory_value: i32 = line.parse().unwrap();
-            current_count += calory_value;
+    let top = input::flag_or_exit::<usize>("--top");
+
+    if env::args().any(|arg| arg == "--stream") {
+        if env::args().any(|arg| arg == "--report" || arg == "--plan") {
+            exit_with(&"--stream cannot be combined with --report or --plan, which need every elf in memory");
         }
+
+        stream(top);
+        return;
     }
 
-    let max_calory_count = calory_count.iter().max().unwrap();
+    let report_format = input::flag_or_exit::<String>("--report");
+    let plan = input::flag_or_exit::<usize>("--plan");
+    let elves = input::parse_or_exit::<Day01>();
 
-    println!("Calory count: {}", max_calory_count);
+    if let Some(format) = report_format {
+        let report =
+            calorie_report(&elves).unwrap_or_else(|| exit_with(&"There are no elves to report on"));
 
-    calory_count.sort();
-    calory_count.reverse();
+        match format.as_str() {
+            "text" => println!("{}", report.render_text()),
+            "json" => println!("{}", report.to_json()),
+            other => exit_with(&format!(
+                "Invalid value '{}' for --report, expected text or json",
+                other
+            )),
+        }
+
+        return;
+    }
 
-    let top3_calory_count: i32 = calory_count.iter().take(3).sum();
+    println!("Calory count: {}", Day01::part_one(&elves));
+    println!("Top 3 calory count: {}", Day01::part_two(&elves));
 
-    println!("Top 3 calory count: {}", top3_calory_count);
+    if let Some(k) = top {
+        print_top(k, &top_k(&elves, k));
+    }
+
+    if let Some(carriers) = plan {
+        print_plan(&elves, carriers);
+    }
 }
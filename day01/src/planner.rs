@@ -0,0 +1,242 @@
+use crate::Elf;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Inputs with at most this many snacks are planned with an exhaustive
+/// search, larger inputs with the longest-processing-time heuristic.
+pub const EXACT_LIMIT: usize = 20;
+
+/// A single snack, identified by the elf that originally carried it and its
+/// position in that elf's list of items.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Snack {
+    pub elf: usize,
+    pub item: usize,
+    pub calories: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Method {
+    Exact,
+    Heuristic,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Plan {
+    pub assignment: Vec<Vec<Snack>>,
+    pub max_load: u128,
+    pub lower_bound: u128,
+    pub method: Method,
+}
+
+impl Plan {
+    pub fn loads(&self) -> Vec<u128> {
+        self.assignment
+            .iter()
+            .map(|snacks| snacks.iter().map(|snack| snack.calories as u128).sum())
+            .collect()
+    }
+
+    /// A heuristic plan is optimal as well when it reaches the lower bound.
+    pub fn is_optimal(&self) -> bool {
+        self.method == Method::Exact || self.max_load == self.lower_bound
+    }
+}
+
+/// No plan can do better than the average load, than carrying the largest
+/// snack, or than carrying two of the `carriers + 1` largest snacks.
+fn lower_bound(calories: &[u128], carriers: usize) -> u128 {
+    let total: u128 = calories.iter().sum();
+    let average = total.div_ceil(carriers as u128);
+    let largest = calories.first().copied().unwrap_or(0);
+    let pigeonhole = match calories.get(carriers) {
+        Some(next) => calories[carriers - 1] + next,
+        None => 0,
+    };
+
+    average.max(largest).max(pigeonhole)
+}
+
+/// Gives each snack, largest first, to the elf with the lowest load so far.
+fn longest_first(calories: &[u128], carriers: usize) -> Vec<usize> {
+    let mut heap: BinaryHeap<Reverse<(u128, usize)>> =
+        (0..carriers).map(|carrier| Reverse((0, carrier))).collect();
+
+    calories
+        .iter()
+        .map(|snack| {
+            let Reverse((load, carrier)) = heap.pop().unwrap();
+            heap.push(Reverse((load + snack, carrier)));
+            carrier
+        })
+        .collect()
+}
+
+struct Search<'a> {
+    calories: &'a [u128],
+    lower_bound: u128,
+    loads: Vec<u128>,
+    assignment: Vec<usize>,
+    best: u128,
+    best_assignment: Vec<usize>,
+}
+
+impl Search<'_> {
+    fn run(&mut self, position: usize) {
+        if self.best == self.lower_bound {
+            return;
+        }
+
+        if position == self.calories.len() {
+            // Every branch that would reach `best` was pruned, so this
+            // assignment is strictly better.
+            self.best = *self.loads.iter().max().unwrap();
+            self.best_assignment = self.assignment.clone();
+            return;
+        }
+
+        let snack = self.calories[position];
+        let mut tried = Vec::new();
+
+        for carrier in 0..self.loads.len() {
+            let load = self.loads[carrier];
+
+            // Elves with the same load are interchangeable.
+            if load + snack >= self.best || tried.contains(&load) {
+                continue;
+            }
+
+            tried.push(load);
+            self.loads[carrier] += snack;
+            self.assignment[position] = carrier;
+
+            self.run(position + 1);
+
+            self.loads[carrier] -= snack;
+        }
+    }
+}
+
+fn max_load(calories: &[u128], assignment: &[usize], carriers: usize) -> u128 {
+    let mut loads = vec![0; carriers];
+
+    for (snack, carrier) in calories.iter().zip(assignment) {
+        loads[*carrier] += snack;
+    }
+
+    loads.into_iter().max().unwrap_or(0)
+}
+
+/// Spreads all snacks over `carriers` elves so that the heaviest load is as
+/// small as possible. Returns `None` when there are no elves to carry them.
+pub fn plan_redistribution(elves: &[Elf], carriers: usize) -> Option<Plan> {
+    if carriers == 0 {
+        return None;
+    }
+
+    let mut snacks: Vec<Snack> = elves
+        .iter()
+        .flat_map(|elf| {
+            elf.items.iter().enumerate().map(|(item, calories)| Snack {
+                elf: elf.index,
+                item,
+                calories: *calories,
+            })
+        })
+        .collect();
+    snacks.sort_by_key(|snack| Reverse(snack.calories));
+
+    let calories: Vec<u128> = snacks.iter().map(|snack| snack.calories as u128).collect();
+    let lower_bound = lower_bound(&calories, carriers);
+
+    let heuristic = longest_first(&calories, carriers);
+    let (assignment, method) = if snacks.len() <= EXACT_LIMIT {
+        let mut search = Search {
+            calories: &calories,
+            lower_bound,
+            loads: vec![0; carriers],
+            assignment: vec![0; calories.len()],
+            best: max_load(&calories, &heuristic, carriers),
+            best_assignment: heuristic,
+        };
+        search.run(0);
+
+        (search.best_assignment, Method::Exact)
+    } else {
+        (heuristic, Method::Heuristic)
+    };
+
+    let mut plan = vec![Vec::new(); carriers];
+
+    for (snack, carrier) in snacks.into_iter().zip(&assignment) {
+        plan[*carrier].push(snack);
+    }
+
+    Some(Plan {
+        max_load: max_load(&calories, &assignment, carriers),
+        assignment: plan,
+        lower_bound,
+        method,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate_input, parse_elves};
+    use aoc_core::rng::Rng;
+
+    #[test]
+    fn exact_plan_beats_the_heuristic() {
+        // Longest-first ends at a max of 15, while {8, 6} / {6, 4, 3} gives
+        // the two elves 14 and 13.
+        let elves = parse_elves("8\n6\n\n6\n4\n\n3\n").unwrap();
+
+        let plan = plan_redistribution(&elves, 2).unwrap();
+
+        assert_eq!(plan.method, Method::Exact);
+        assert_eq!(plan.max_load, 14);
+        assert_eq!(plan.lower_bound, 14);
+        assert!(plan.is_optimal());
+    }
+
+    #[test]
+    fn exact_plan_finds_perfect_splits_longest_first_misses() {
+        // Longest-first puts 3 and 3 on different elves and ends at 7, but
+        // {3, 3} / {2, 2, 2} gives both elves 6.
+        let elves = parse_elves("3\n3\n2\n2\n2").unwrap();
+
+        let plan = plan_redistribution(&elves, 2).unwrap();
+
+        assert_eq!(plan.max_load, 6);
+        assert_eq!(plan.loads(), vec![6, 6]);
+    }
+
+    #[test]
+    fn plan_keeps_every_snack() {
+        let elves = parse_elves(&generate_input(&mut Rng::new(15), 40)).unwrap();
+        let snack_count: usize = elves.iter().map(|elf| elf.items.len()).sum();
+
+        let plan = plan_redistribution(&elves, 7).unwrap();
+        let loads = plan.loads();
+
+        assert_eq!(plan.method, Method::Heuristic);
+        assert_eq!(
+            plan.assignment.iter().map(Vec::len).sum::<usize>(),
+            snack_count
+        );
+        assert_eq!(
+            loads.iter().sum::<u128>(),
+            elves.iter().map(|elf| elf.total() as u128).sum()
+        );
+        assert_eq!(plan.max_load, *loads.iter().max().unwrap());
+        assert!(plan.max_load >= plan.lower_bound);
+    }
+
+    #[test]
+    fn plan_needs_carriers() {
+        let elves = parse_elves("1\n").unwrap();
+
+        assert_eq!(plan_redistribution(&elves, 0), None);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::Elf;
+use aoc_core::json::{self, JsonObject};
+
+/// Elves whose total is at least this many standard deviations away from the
+/// mean are listed as outliers.
+pub const OUTLIER_Z_SCORE: f64 = 3.0;
+
+const PERCENTILES: [u32; 6] = [10, 25, 50, 75, 90, 99];
+const HISTOGRAM_BUCKETS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Distribution {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Outlier {
+    pub index: usize,
+    pub total: u64,
+    pub z_score: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Bucket {
+    pub low: f64,
+    pub high: f64,
+    pub count: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CalorieReport {
+    pub elves: usize,
+    pub totals: Distribution,
+    pub percentiles: Vec<(u32, f64)>,
+    pub items_per_elf: Distribution,
+    pub outliers: Vec<Outlier>,
+    pub histogram: Vec<Bucket>,
+}
+
+/// Returns the value below which `percentile` percent of the sorted values
+/// fall, interpolating linearly between the two closest ranks.
+fn percentile(sorted: &[f64], percentile: u32) -> f64 {
+    let rank = (sorted.len() - 1) as f64 * percentile as f64 / 100.0;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+impl Distribution {
+    /// Describes a non-empty set of values. The standard deviation is the
+    /// population standard deviation, as every elf is part of the data.
+    fn new(values: &[f64]) -> Distribution {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        let variance = sorted
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / sorted.len() as f64;
+
+        Distribution {
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            mean,
+            median: percentile(&sorted, 50),
+            std_dev: variance.sqrt(),
+        }
+    }
+
+    fn to_json(&self) -> JsonObject {
+        JsonObject::new()
+            .number("min", self.min)
+            .number("max", self.max)
+            .number("mean", self.mean)
+            .number("median", self.median)
+            .number("std_dev", self.std_dev)
+    }
+}
+
+fn histogram(totals: &[f64], min: f64, max: f64) -> Vec<Bucket> {
+    let width = ((max - min) / HISTOGRAM_BUCKETS as f64).max(1.0);
+    let bucket_count = (((max - min) / width).ceil() as usize).clamp(1, HISTOGRAM_BUCKETS);
+
+    let mut buckets: Vec<Bucket> = (0..bucket_count)
+        .map(|bucket| Bucket {
+            low: min + width * bucket as f64,
+            high: min + width * (bucket + 1) as f64,
+            count: 0,
+        })
+        .collect();
+
+    for total in totals {
+        // The maximum belongs in the last bucket rather than one of its own.
+        let bucket = (((total - min) / width) as usize).min(bucket_count - 1);
+        buckets[bucket].count += 1;
+    }
+
+    buckets
+}
+
+/// Builds the statistics report for the parsed elves, or `None` when there
+/// are no elves to describe.
+pub fn calorie_report(elves: &[Elf]) -> Option<CalorieReport> {
+    if elves.is_empty() {
+        return None;
+    }
+
+    let totals: Vec<f64> = elves.iter().map(|elf| elf.total() as f64).collect();
+    let items: Vec<f64> = elves.iter().map(|elf| elf.items.len() as f64).collect();
+
+    let distribution = Distribution::new(&totals);
+
+    let mut sorted = totals.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let outliers = elves
+        .iter()
+        .filter(|_| distribution.std_dev > 0.0)
+        .map(|elf| Outlier {
+            index: elf.index,
+            total: elf.total(),
+            z_score: (elf.total() as f64 - distribution.mean) / distribution.std_dev,
+        })
+        .filter(|outlier| outlier.z_score.abs() >= OUTLIER_Z_SCORE)
+        .collect();
+
+    Some(CalorieReport {
+        elves: elves.len(),
+        percentiles: PERCENTILES
+            .iter()
+            .map(|p| (*p, percentile(&sorted, *p)))
+            .collect(),
+        items_per_elf: Distribution::new(&items),
+        outliers,
+        histogram: histogram(&totals, distribution.min, distribution.max),
+        totals: distribution,
+    })
+}
+
+impl CalorieReport {
+    pub fn render_text(&self) -> String {
+        let mut lines = vec![format!("Elves: {}", self.elves)];
+
+        let describe = |label: &str, d: &Distribution| {
+            format!(
+                "{}: min {}, max {}, mean {:.1}, median {:.1}, std dev {:.1}",
+                label, d.min, d.max, d.mean, d.median, d.std_dev
+            )
+        };
+
+        lines.push(describe("Calories", &self.totals));
+        lines.push(describe("Items per elf", &self.items_per_elf));
+
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(p, value)| format!("p{} {:.1}", p, value))
+            .collect();
+        lines.push(format!("Percentiles: {}", percentiles.join(", ")));
+
+        lines.push(format!("Outliers (|z| >= {:.1}):", OUTLIER_Z_SCORE));
+
+        if self.outliers.is_empty() {
+            lines.push("  none".to_string());
+        }
+
+        for outlier in &self.outliers {
+            lines.push(format!(
+                "  elf {}: {} calories (z = {:.2})",
+                outlier.index, outlier.total, outlier.z_score
+            ));
+        }
+
+        lines.push("Histogram:".to_string());
+
+        let largest = self
+            .histogram
+            .iter()
+            .map(|bucket| bucket.count)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        for bucket in &self.histogram {
+            let bar = "#".repeat(bucket.count * HISTOGRAM_WIDTH / largest);
+            lines.push(format!(
+                "  {:>8.0} - {:>8.0} | {:<width$} {}",
+                bucket.low,
+                bucket.high,
+                bar,
+                bucket.count,
+                width = HISTOGRAM_WIDTH
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    pub fn to_json(&self) -> JsonObject {
+        let percentiles = self.percentiles.iter().map(|(p, value)| {
+            JsonObject::new()
+                .number("percentile", p)
+                .number("value", value)
+                .to_string()
+        });
+
+        let outliers = self.outliers.iter().map(|outlier| {
+            JsonObject::new()
+                .number("index", outlier.index)
+                .number("total", outlier.total)
+                .number("z_score", outlier.z_score)
+                .to_string()
+        });
+
+        let histogram = self.histogram.iter().map(|bucket| {
+            JsonObject::new()
+                .number("low", bucket.low)
+                .number("high", bucket.high)
+                .number("count", bucket.count)
+                .to_string()
+        });
+
+        JsonObject::new()
+            .number("elves", self.elves)
+            .raw("totals", self.totals.to_json().to_string())
+            .raw("percentiles", json::array(percentiles))
+            .raw("items_per_elf", self.items_per_elf.to_json().to_string())
+            .raw("outliers", json::array(outliers))
+            .raw("histogram", json::array(histogram))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_elves;
+
+    #[test]
+    fn calorie_report_describes_the_totals() {
+        let elves = parse_elves(include_str!("../data/example.txt")).unwrap();
+        let report = calorie_report(&elves).unwrap();
+
+        assert_eq!(report.elves, 5);
+        assert_eq!(report.totals.min, 4000.0);
+        assert_eq!(report.totals.max, 24000.0);
+        assert_eq!(report.totals.mean, 11000.0);
+        assert_eq!(report.totals.median, 10000.0);
+        assert_eq!(report.items_per_elf.max, 3.0);
+        assert_eq!(report.percentiles[1], (25, 6000.0));
+        assert_eq!(
+            report
+                .histogram
+                .iter()
+                .map(|bucket| bucket.count)
+                .sum::<usize>(),
+            5
+        );
+    }
+
+    #[test]
+    fn calorie_report_lists_outliers() {
+        let input_data: Vec<String> = (0..20)
+            .map(|_| "100".to_string())
+            .chain(["5000".to_string()])
+            .collect();
+        let elves = parse_elves(&input_data.join("\n\n")).unwrap();
+        let report = calorie_report(&elves).unwrap();
+
+        assert_eq!(report.outliers.len(), 1);
+        assert_eq!(report.outliers[0].index, 20);
+    }
+
+    #[test]
+    fn calorie_report_needs_elves() {
+        assert_eq!(calorie_report(&[]), None);
+    }
+
+    #[test]
+    fn percentile_interpolates_between_ranks() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(percentile(&sorted, 50), 2.5);
+        assert_eq!(percentile(&sorted, 100), 4.0);
+    }
+}
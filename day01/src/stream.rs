@@ -0,0 +1,169 @@
+use crate::{parse_item, RankedElf, TopK};
+use aoc_core::{Answer, ParseError};
+use std::fmt::Display;
+use std::io::{self, BufRead};
+
+/// The result of a streaming pass over the input, see [`scan_elves`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ElfScan {
+    pub elves: usize,
+    pub max: Option<RankedElf>,
+    pub top: Vec<RankedElf>,
+}
+
+impl ElfScan {
+    /// The same answer as [`crate::Day01`] gives for part one, 0 without elves.
+    pub fn part_one(&self) -> Answer {
+        self.max.map_or(0, |elf| elf.total).into()
+    }
+
+    /// The same answer as [`crate::Day01`] gives for part two. Needs a scan
+    /// that kept at least the top three elves.
+    pub fn part_two(&self) -> Answer {
+        self.top.iter().take(3).map(|elf| elf.total as i128).sum::<i128>().into()
+    }
+}
+
+#[derive(Debug)]
+pub enum ScanError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScanError::Io(error) => write!(f, "Unable to read the input: {}", error),
+            ScanError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+impl From<io::Error> for ScanError {
+    fn from(error: io::Error) -> Self {
+        ScanError::Io(error)
+    }
+}
+
+impl From<ParseError> for ScanError {
+    fn from(error: ParseError) -> Self {
+        ScanError::Parse(error)
+    }
+}
+
+/// Reads the elves one line at a time and only keeps the running total of
+/// the current elf and the `k` heaviest elves, so memory use does not grow
+/// with the size of the input. Accepts the same input as [`crate::parse_elves`].
+pub fn scan_elves<R: BufRead>(mut reader: R, k: usize) -> Result<ElfScan, ScanError> {
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    let mut elves = 0;
+    let mut total = 0;
+    let mut has_items = false;
+    let mut max: Option<RankedElf> = None;
+    let mut top = TopK::new(k);
+
+    let mut finish_elf = |index: usize, total: u64| {
+        if max.is_none_or(|elf| total > elf.total) {
+            max = Some(RankedElf { index, total });
+        }
+
+        top.push(index, total);
+    };
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        line_number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        match parse_item(line_number, text, total)? {
+            Some(calories) => {
+                total += calories;
+                has_items = true;
+            }
+            None if has_items => {
+                finish_elf(elves, total);
+                elves += 1;
+                total = 0;
+                has_items = false;
+            }
+            None => {}
+        }
+    }
+
+    if has_items {
+        finish_elf(elves, total);
+        elves += 1;
+    }
+
+    Ok(ElfScan {
+        elves,
+        max,
+        top: top.into_ranked(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate_input, parse_elves, top_k, Day01, Elf};
+    use aoc_core::rng::Rng;
+    use aoc_core::Solution;
+
+    #[test]
+    fn scan_elves_matches_the_parsed_elves() {
+        let input_data = generate_input(&mut Rng::new(14), 300);
+        let elves = parse_elves(&input_data).unwrap();
+
+        let scan = scan_elves(input_data.as_bytes(), 5).unwrap();
+
+        assert_eq!(scan.elves, elves.len());
+        assert_eq!(
+            scan.max.unwrap().total,
+            elves.iter().map(Elf::total).max().unwrap()
+        );
+        assert_eq!(scan.top, top_k(&elves, 5));
+    }
+
+    #[test]
+    fn scans_answer_like_the_solution() {
+        for input_data in ["", "\n\n", "5\n\n7", &generate_input(&mut Rng::new(3), 50)] {
+            let elves = parse_elves(input_data).unwrap();
+            let scan = scan_elves(input_data.as_bytes(), 3).unwrap();
+
+            assert_eq!(scan.part_one(), Day01::part_one(&elves));
+            assert_eq!(scan.part_two(), Day01::part_two(&elves));
+        }
+    }
+
+    #[test]
+    fn scan_elves_handles_crlf_without_a_trailing_separator() {
+        let scan = scan_elves("1000\r\n2000\r\n \r\n5000".as_bytes(), 1).unwrap();
+
+        assert_eq!(scan.elves, 2);
+        assert_eq!(
+            scan.max,
+            Some(RankedElf {
+                index: 1,
+                total: 5000
+            })
+        );
+    }
+
+    #[test]
+    fn scan_elves_reports_line_numbers() {
+        let error = scan_elves("1\n2\n\nx\n".as_bytes(), 3).unwrap_err();
+
+        assert!(
+            matches!(error, ScanError::Parse(error) if error == ParseError::new(4, 1, "x", "a calorie count"))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{score_round, Outcome, Round, Shape};
+use std::fmt::Display;
+
+/// One way to read the X, Y and Z of the strategy guide, in that order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mapping {
+    Shapes([Shape; 3]),
+    Outcomes([Outcome; 3]),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RankedMapping {
+    pub mapping: Mapping,
+    pub score: i32,
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = match self {
+            Mapping::Shapes(shapes) => shapes.iter().map(|shape| format!("{:?}", shape)).collect(),
+            Mapping::Outcomes(outcomes) => outcomes
+                .iter()
+                .map(|outcome| format!("{:?}", outcome))
+                .collect(),
+        };
+
+        write!(f, "X={} Y={} Z={}", names[0], names[1], names[2])
+    }
+}
+
+fn permutations<T: Copy>(items: [T; 3]) -> Vec<[T; 3]> {
+    let [a, b, c] = items;
+    vec![
+        [a, b, c],
+        [a, c, b],
+        [b, a, c],
+        [b, c, a],
+        [c, a, b],
+        [c, b, a],
+    ]
+}
+
+/// Every bijection from X, Y and Z to shapes, followed by every bijection to
+/// outcomes. The puzzle's own readings come first in their group.
+pub fn all_mappings() -> Vec<Mapping> {
+    let shapes = permutations(Shape::ALL).into_iter().map(Mapping::Shapes);
+    let outcomes = permutations(Outcome::ALL)
+        .into_iter()
+        .map(Mapping::Outcomes);
+
+    shapes.chain(outcomes).collect()
+}
+
+impl Mapping {
+    pub fn score(&self, rounds: &[Round]) -> i32 {
+        rounds
+            .iter()
+            .map(|round| {
+                let me = match self {
+                    Mapping::Shapes(shapes) => shapes[round.column()],
+                    Mapping::Outcomes(outcomes) => {
+                        Shape::for_outcome(round.opponent, outcomes[round.column()])
+                    }
+                };
+
+                score_round(round.opponent, me)
+            })
+            .sum()
+    }
+}
+
+/// Scores the guide under all twelve mappings, highest score first. Mappings
+/// with the same score stay in the order of [`all_mappings`].
+pub fn rank_mappings(rounds: &[Round]) -> Vec<RankedMapping> {
+    let mut ranked: Vec<RankedMapping> = all_mappings()
+        .into_iter()
+        .map(|mapping| RankedMapping {
+            score: mapping.score(rounds),
+            mapping,
+        })
+        .collect();
+
+    ranked.sort_by_key(|ranked| -ranked.score);
+    ranked
+}
+
+/// The mappings under which the guide adds up to exactly `score`.
+pub fn mappings_with_score(rounds: &[Round], score: i32) -> Vec<Mapping> {
+    all_mappings()
+        .into_iter()
+        .filter(|mapping| mapping.score(rounds) == score)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_score, calculate_score_cheating, parse_instructions};
+
+    #[test]
+    fn all_mappings_include_both_puzzle_readings() {
+        let mappings = all_mappings();
+        let rounds = parse_instructions(include_str!("../data/example.txt")).unwrap();
+
+        assert_eq!(mappings.len(), 12);
+        assert_eq!(mappings[0].score(&rounds), calculate_score(&rounds));
+        assert_eq!(
+            mappings[6].score(&rounds),
+            calculate_score_cheating(&rounds)
+        );
+        assert_eq!(mappings[0].to_string(), "X=Rock Y=Paper Z=Scissors");
+    }
+
+    #[test]
+    fn rank_mappings_puts_the_best_reading_first() {
+        // Always answering rock with paper wins every round.
+        let rounds = parse_instructions("A X\nA X\n").unwrap();
+
+        let ranked = rank_mappings(&rounds);
+
+        assert_eq!(ranked[0].score, 16);
+        assert!(ranked.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn mappings_with_score_answers_the_inverse_question() {
+        let rounds = parse_instructions(include_str!("../data/example.txt")).unwrap();
+
+        let mappings = mappings_with_score(&rounds, 15);
+
+        assert!(mappings.contains(&Mapping::Shapes(Shape::ALL)));
+        assert!(mappings.iter().all(|mapping| mapping.score(&rounds) == 15));
+        assert!(mappings_with_score(&rounds, 1000).is_empty());
+    }
+}
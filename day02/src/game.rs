@@ -0,0 +1,538 @@
+use crate::Outcome;
+use aoc_core::ParseError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const ROCK_PAPER_SCISSORS: &str = "\
+# Rock-Paper-Scissors, as played in the puzzle.
+shape Rock 1 A X
+shape Paper 2 B Y
+shape Scissors 3 C Z
+beats Rock Scissors
+beats Paper Rock
+beats Scissors Paper
+outcomes X Y Z
+";
+
+/// Rock-Paper-Scissors-Lizard-Spock. Our codes run from V to Z, so X, Y and
+/// Z keep their meaning as lose, draw and win in the outcome reading.
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "\
+shape Rock 1 A V
+shape Paper 2 B W
+shape Scissors 3 C X
+shape Lizard 4 D Y
+shape Spock 5 E Z
+beats Scissors Paper
+beats Paper Rock
+beats Rock Lizard
+beats Lizard Spock
+beats Spock Scissors
+beats Scissors Lizard
+beats Lizard Paper
+beats Paper Spock
+beats Spock Rock
+beats Rock Scissors
+outcomes X Y Z
+";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ShapeDefinition {
+    pub name: String,
+    pub score: i32,
+    pub opponent_code: String,
+    pub code: String,
+}
+
+/// The two ways to read the second column of the strategy guide.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Reading {
+    Shapes,
+    Outcomes,
+}
+
+impl FromStr for Reading {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "shapes" => Ok(Reading::Shapes),
+            "outcomes" => Ok(Reading::Outcomes),
+            _ => Err(format!(
+                "Unknown reading {}, expected shapes or outcomes",
+                text
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GameError {
+    Parse(ParseError),
+    NoShapes,
+    DuplicateName(String),
+    DuplicateCode(String),
+    UnknownShape(String),
+    BeatsItself(String),
+    BeatEachOther(String, String),
+    Undecided(String, String),
+    NoWinOrLoss(String),
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::Parse(error) => write!(f, "{}", error),
+            GameError::NoShapes => write!(f, "The game has no shapes"),
+            GameError::DuplicateName(name) => write!(f, "The shape {} is defined twice", name),
+            GameError::DuplicateCode(code) => write!(f, "The code {} is used twice", code),
+            GameError::UnknownShape(name) => write!(f, "Unknown shape {}", name),
+            GameError::BeatsItself(name) => write!(f, "{} cannot beat itself", name),
+            GameError::BeatEachOther(a, b) => write!(f, "{} and {} cannot beat each other", a, b),
+            GameError::Undecided(a, b) => write!(f, "Neither {} nor {} beats the other", a, b),
+            GameError::NoWinOrLoss(name) => {
+                write!(
+                    f,
+                    "{} must beat at least one shape and lose to another",
+                    name
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<ParseError> for GameError {
+    fn from(error: ParseError) -> Self {
+        GameError::Parse(error)
+    }
+}
+
+/// A cyclic game in the style of Rock-Paper-Scissors. Every pair of distinct
+/// shapes has exactly one winner and every shape beats at least one shape and
+/// loses to another, which [`Game::new`] checks.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Game {
+    shapes: Vec<ShapeDefinition>,
+    beats: Vec<Vec<bool>>,
+    outcome_codes: [String; 3],
+}
+
+/// A line of the strategy guide in terms of a [`Game`]'s shapes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GameRound {
+    pub opponent: usize,
+    pub response: GameResponse,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GameResponse {
+    Shape(usize),
+    Outcome(Outcome),
+}
+
+impl Game {
+    pub fn new(
+        shapes: Vec<ShapeDefinition>,
+        beats: &[(&str, &str)],
+        outcome_codes: [&str; 3],
+    ) -> Result<Game, GameError> {
+        if shapes.is_empty() {
+            return Err(GameError::NoShapes);
+        }
+
+        for (index, shape) in shapes.iter().enumerate() {
+            if shapes[..index].iter().any(|other| other.name == shape.name) {
+                return Err(GameError::DuplicateName(shape.name.clone()));
+            }
+        }
+
+        let mut opponent_codes: Vec<&str> = Vec::new();
+        let mut codes: Vec<&str> = Vec::new();
+
+        for shape in &shapes {
+            if opponent_codes.contains(&shape.opponent_code.as_str()) {
+                return Err(GameError::DuplicateCode(shape.opponent_code.clone()));
+            }
+
+            if codes.contains(&shape.code.as_str()) {
+                return Err(GameError::DuplicateCode(shape.code.clone()));
+            }
+
+            opponent_codes.push(&shape.opponent_code);
+            codes.push(&shape.code);
+        }
+
+        for (index, code) in outcome_codes.iter().enumerate() {
+            if outcome_codes[..index].contains(code) {
+                return Err(GameError::DuplicateCode(code.to_string()));
+            }
+        }
+
+        let find = |name: &str| {
+            shapes
+                .iter()
+                .position(|shape| shape.name == name)
+                .ok_or_else(|| GameError::UnknownShape(name.to_string()))
+        };
+
+        let mut relation = vec![vec![false; shapes.len()]; shapes.len()];
+
+        for (winner, loser) in beats {
+            let (winner, loser) = (find(winner)?, find(loser)?);
+
+            if winner == loser {
+                return Err(GameError::BeatsItself(shapes[winner].name.clone()));
+            }
+
+            relation[winner][loser] = true;
+        }
+
+        for a in 0..shapes.len() {
+            for b in a + 1..shapes.len() {
+                let (name_a, name_b) = (shapes[a].name.clone(), shapes[b].name.clone());
+
+                match (relation[a][b], relation[b][a]) {
+                    (true, true) => return Err(GameError::BeatEachOther(name_a, name_b)),
+                    (false, false) => return Err(GameError::Undecided(name_a, name_b)),
+                    _ => {}
+                }
+            }
+        }
+
+        // Without this, some round could not be lost or won on purpose.
+        for (index, shape) in shapes.iter().enumerate() {
+            let wins = relation[index].iter().any(|beats| *beats);
+            let losses = relation.iter().any(|row| row[index]);
+
+            if !wins || !losses {
+                return Err(GameError::NoWinOrLoss(shape.name.clone()));
+            }
+        }
+
+        Ok(Game {
+            shapes,
+            beats: relation,
+            outcome_codes: outcome_codes.map(|code| code.to_string()),
+        })
+    }
+
+    /// Reads a game definition: one `shape <name> <score> <opponent code>
+    /// <code>` line per shape, one `beats <winner> <loser>` line per pair of
+    /// shapes and an `outcomes <lose> <draw> <win>` line. Empty lines and
+    /// lines starting with `#` are ignored.
+    pub fn parse(text: &str) -> Result<Game, GameError> {
+        let mut shapes = Vec::new();
+        let mut beats = Vec::new();
+        let mut outcome_codes = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let words: Vec<&str> = line.split_whitespace().collect();
+
+            match words.as_slice() {
+                [] => {}
+                [comment, ..] if comment.starts_with('#') => {}
+                ["shape", name, score, opponent_code, code] => {
+                    let score = score
+                        .parse()
+                        .map_err(|_| ParseError::in_line(line_number, line, score, "a score"))?;
+
+                    shapes.push(ShapeDefinition {
+                        name: name.to_string(),
+                        score,
+                        opponent_code: opponent_code.to_string(),
+                        code: code.to_string(),
+                    });
+                }
+                ["beats", winner, loser] => beats.push((*winner, *loser)),
+                ["outcomes", lose, draw, win] => outcome_codes = Some([*lose, *draw, *win]),
+                _ => {
+                    let expected = "`shape <name> <score> <opponent code> <code>`, `beats <winner> <loser>` or `outcomes <lose> <draw> <win>`";
+                    return Err(
+                        ParseError::in_line(line_number, line, line.trim(), expected).into(),
+                    );
+                }
+            }
+        }
+
+        let outcome_codes = outcome_codes.ok_or_else(|| {
+            let line_number = text.lines().count() + 1;
+            ParseError::new(line_number, 1, "", "an `outcomes <lose> <draw> <win>` line")
+        })?;
+
+        Game::new(shapes, &beats, outcome_codes)
+    }
+
+    pub fn rock_paper_scissors() -> Game {
+        Game::parse(ROCK_PAPER_SCISSORS).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap()
+    }
+
+    pub fn built_in(name: &str) -> Option<Game> {
+        match name {
+            "rps" => Some(Game::rock_paper_scissors()),
+            "rpsls" => Some(Game::rock_paper_scissors_lizard_spock()),
+            _ => None,
+        }
+    }
+
+    pub fn shapes(&self) -> &[ShapeDefinition] {
+        &self.shapes
+    }
+
+    pub fn beats(&self, winner: usize, loser: usize) -> bool {
+        self.beats[winner][loser]
+    }
+
+    pub fn outcome(&self, me: usize, opponent: usize) -> Outcome {
+        if me == opponent {
+            Outcome::Draw
+        } else if self.beats(me, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn score(&self, opponent: usize, me: usize) -> i32 {
+        self.shapes[me].score + self.outcome(me, opponent).score()
+    }
+
+    /// The shape to play against `opponent` for the given outcome. When more
+    /// than one shape gives that outcome, the one worth the most points is
+    /// played.
+    pub fn shape_for_outcome(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|me| self.outcome(*me, opponent) == outcome)
+            .max_by_key(|me| self.shapes[*me].score)
+            .expect("every cyclic game has a shape for each outcome")
+    }
+
+    /// Splits a line of the guide into the opponent's shape and our code.
+    fn parse_opponent<'a>(
+        &self,
+        line_number: usize,
+        line: &'a str,
+    ) -> Result<(usize, &'a str), ParseError> {
+        let (opponent, me) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::in_line(line_number, line, line, "two moves like `A Y`"))?;
+
+        let opponent = self
+            .shapes
+            .iter()
+            .position(|shape| shape.opponent_code == opponent)
+            .ok_or_else(|| ParseError::in_line(line_number, line, opponent, "an opponent move"))?;
+
+        Ok((opponent, me))
+    }
+
+    fn parse_round(
+        &self,
+        line_number: usize,
+        line: &str,
+        reading: Reading,
+    ) -> Result<GameRound, ParseError> {
+        let (opponent, me) = self.parse_opponent(line_number, line)?;
+
+        let response = match reading {
+            Reading::Shapes => self
+                .shapes
+                .iter()
+                .position(|shape| shape.code == me)
+                .map(GameResponse::Shape),
+            Reading::Outcomes => self
+                .outcome_codes
+                .iter()
+                .position(|code| code == me)
+                .map(|index| GameResponse::Outcome(Outcome::ALL[index])),
+        };
+
+        let response =
+            response.ok_or_else(|| ParseError::in_line(line_number, line, me, "a response"))?;
+
+        Ok(GameRound { opponent, response })
+    }
+
+    pub fn parse_guide(
+        &self,
+        input_data: &str,
+        reading: Reading,
+    ) -> Result<Vec<GameRound>, ParseError> {
+        input_data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| self.parse_round(index + 1, line, reading))
+            .collect()
+    }
+
+    /// Reads only the opponent's moves from the guide, whatever the second
+    /// column means.
+    pub fn parse_opponents(&self, input_data: &str) -> Result<Vec<usize>, ParseError> {
+        input_data
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Ok(self.parse_opponent(index + 1, line)?.0))
+            .collect()
+    }
+
+    pub fn shape_to_play(&self, round: &GameRound) -> usize {
+        match round.response {
+            GameResponse::Shape(me) => me,
+            GameResponse::Outcome(outcome) => self.shape_for_outcome(round.opponent, outcome),
+        }
+    }
+
+    pub fn total_score(&self, rounds: &[GameRound]) -> i32 {
+        rounds
+            .iter()
+            .map(|round| self.score(round.opponent, self.shape_to_play(round)))
+            .sum()
+    }
+
+    /// Scores the strategy guide under the given reading of its second column.
+    pub fn score_guide(&self, input_data: &str, reading: Reading) -> Result<i32, ParseError> {
+        Ok(self.total_score(&self.parse_guide(input_data, reading)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_score, calculate_score_cheating, generate_input, parse_instructions};
+    use aoc_core::rng::Rng;
+
+    fn shape(name: &str, score: i32, opponent_code: &str, code: &str) -> ShapeDefinition {
+        ShapeDefinition {
+            name: name.to_string(),
+            score,
+            opponent_code: opponent_code.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_agrees_with_the_puzzle_scoring() {
+        let game = Game::rock_paper_scissors();
+        let input_data = generate_input(&mut Rng::new(17), 500);
+        let rounds = parse_instructions(&input_data).unwrap();
+
+        assert_eq!(
+            game.score_guide(&input_data, Reading::Shapes),
+            Ok(calculate_score(&rounds))
+        );
+        assert_eq!(
+            game.score_guide(&input_data, Reading::Outcomes),
+            Ok(calculate_score_cheating(&rounds))
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_is_balanced() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+
+        for me in 0..5 {
+            let wins = (0..5).filter(|opponent| game.beats(me, *opponent)).count();
+            assert_eq!(wins, 2, "{}", game.shapes()[me].name);
+        }
+
+        // Spock vaporizes Rock, and Lizard is the best paying win against Paper.
+        assert_eq!(game.score_guide("A Z\n", Reading::Shapes), Ok(5 + 6));
+        assert_eq!(game.score_guide("B Z\n", Reading::Outcomes), Ok(4 + 6));
+    }
+
+    #[test]
+    fn new_rejects_inconsistent_relations() {
+        let shapes = vec![shape("Rock", 1, "A", "X"), shape("Paper", 2, "B", "Y")];
+
+        assert_eq!(
+            Game::new(shapes.clone(), &[], ["X", "Y", "Z"]),
+            Err(GameError::Undecided(
+                "Rock".to_string(),
+                "Paper".to_string()
+            ))
+        );
+        assert_eq!(
+            Game::new(
+                shapes.clone(),
+                &[("Rock", "Paper"), ("Paper", "Rock")],
+                ["X", "Y", "Z"]
+            ),
+            Err(GameError::BeatEachOther(
+                "Rock".to_string(),
+                "Paper".to_string()
+            ))
+        );
+        assert_eq!(
+            Game::new(shapes.clone(), &[("Rock", "Rock")], ["X", "Y", "Z"]),
+            Err(GameError::BeatsItself("Rock".to_string()))
+        );
+        assert_eq!(
+            Game::new(shapes, &[("Rock", "Lizard")], ["X", "Y", "Z"]),
+            Err(GameError::UnknownShape("Lizard".to_string()))
+        );
+    }
+
+    #[test]
+    fn new_rejects_shapes_that_cannot_win_and_lose() {
+        let two_shapes = vec![shape("Rock", 1, "A", "Y"), shape("Paper", 2, "B", "Z")];
+        let one_shape = vec![shape("Rock", 1, "A", "Y")];
+        let transitive = vec![
+            shape("Rock", 1, "A", "X"),
+            shape("Paper", 2, "B", "Y"),
+            shape("Scissors", 3, "C", "Z"),
+        ];
+
+        assert_eq!(
+            Game::new(two_shapes, &[("Rock", "Paper")], ["X", "Y", "Z"]),
+            Err(GameError::NoWinOrLoss("Rock".to_string()))
+        );
+        assert_eq!(
+            Game::new(one_shape, &[], ["X", "Y", "Z"]),
+            Err(GameError::NoWinOrLoss("Rock".to_string()))
+        );
+        assert_eq!(
+            Game::new(
+                transitive,
+                &[
+                    ("Rock", "Paper"),
+                    ("Rock", "Scissors"),
+                    ("Paper", "Scissors")
+                ],
+                ["X", "Y", "Z"]
+            ),
+            Err(GameError::NoWinOrLoss("Rock".to_string()))
+        );
+
+        let error =
+            Game::parse("shape Rock 1 A Y\nshape Paper 2 B Z\nbeats Rock Paper\noutcomes X Y Z\n");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Rock must beat at least one shape and lose to another"
+        );
+    }
+
+    #[test]
+    fn new_rejects_duplicate_codes() {
+        let shapes = vec![shape("Rock", 1, "A", "X"), shape("Paper", 2, "A", "Y")];
+
+        assert_eq!(
+            Game::new(shapes, &[("Paper", "Rock")], ["X", "Y", "Z"]),
+            Err(GameError::DuplicateCode("A".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_reports_invalid_lines() {
+        let error = Game::parse("shape Rock one A X\n").unwrap_err();
+
+        assert_eq!(
+            error,
+            GameError::Parse(ParseError::new(1, 12, "one", "a score"))
+        );
+    }
+}
@@ -0,0 +1,303 @@
+mod cipher;
+mod game;
+mod strategy;
+mod tournament;
+mod trace;
+
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Solution};
+use std::str::FromStr;
+
+pub use cipher::{all_mappings, mappings_with_score, rank_mappings, Mapping, RankedMapping};
+pub use game::{
+    Game, GameError, GameResponse, GameRound, Reading, ShapeDefinition, ROCK_PAPER_SCISSORS,
+    ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+};
+pub use strategy::{
+    best_response, equilibrium, expected_score, opponent_distribution, BestResponse, Equilibrium,
+    TooManyShapes, MAX_EQUILIBRIUM_SHAPES,
+};
+pub use tournament::{run_tournament, Bot, Pairing, ScoreSummary, TournamentOptions};
+pub use trace::{trace, TraceStep};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    pub fn score(self) -> i32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    /// The shape that this shape defeats.
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape that defeats this shape.
+    pub fn beaten_by(self) -> Shape {
+        Shape::ALL.into_iter().find(|shape| shape.beats() == self).unwrap()
+    }
+
+    pub fn outcome_against(self, opponent: Shape) -> Outcome {
+        if self == opponent {
+            Outcome::Draw
+        } else if self.beats() == opponent {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// The shape to play against `opponent` to get the given outcome.
+    pub fn for_outcome(opponent: Shape, outcome: Outcome) -> Shape {
+        match outcome {
+            Outcome::Lose => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.beaten_by(),
+        }
+    }
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// Reads the opponent's `A`, `B` and `C` as well as the `X`, `Y` and `Z` of
+/// the first reading of the strategy guide.
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Shape::Rock),
+            "B" | "Y" => Ok(Shape::Paper),
+            "C" | "Z" => Ok(Shape::Scissors),
+            _ => Err(format!("Unknown shape '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Outcome::Lose),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(format!("Unknown outcome '{}'", s)),
+        }
+    }
+}
+
+/// A line of the strategy guide. The second column is decoded both as the
+/// shape to play (part one) and as the outcome to aim for (part two).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Round {
+    pub opponent: Shape,
+    pub shape: Shape,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    /// The position of the second column's code: 0, 1 and 2 for X, Y and Z.
+    pub fn column(&self) -> usize {
+        Outcome::ALL.iter().position(|outcome| *outcome == self.outcome).unwrap()
+    }
+}
+
+pub fn score_round(opponent: Shape, me: Shape) -> i32 {
+    me.score() + me.outcome_against(opponent).score()
+}
+
+pub fn calculate_score_cheating(rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| score_round(round.opponent, Shape::for_outcome(round.opponent, round.outcome)))
+        .sum()
+}
+
+pub fn calculate_score(rounds: &[Round]) -> i32 {
+    rounds.iter().map(|round| score_round(round.opponent, round.shape)).sum()
+}
+
+fn parse_instruction(line_number: usize, line: &str) -> Result<Round, ParseError> {
+    let (opponent, me) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::in_line(line_number, line, line, "two moves like `A Y`"))?;
+
+    if !matches!(opponent, "A" | "B" | "C") {
+        return Err(ParseError::in_line(line_number, line, opponent, "an opponent move A, B or C"));
+    }
+
+    let outcome: Outcome = me
+        .parse()
+        .map_err(|_| ParseError::in_line(line_number, line, me, "a response X, Y or Z"))?;
+
+    Ok(Round {
+        opponent: opponent.parse().unwrap(),
+        shape: me.parse().unwrap(),
+        outcome,
+    })
+}
+
+pub fn parse_instructions(input_data: &str) -> Result<Vec<Round>, ParseError> {
+    input_data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_instruction(index + 1, line))
+        .collect()
+}
+
+/// Writes a strategy guide of `rounds` random lines.
+pub fn generate_input(rng: &mut Rng, rounds: usize) -> String {
+    let opponent_moves = ["A", "B", "C"];
+    let responses = ["X", "Y", "Z"];
+
+    (0..rounds.max(1))
+        .map(|_| format!("{} {}\n", rng.choose(&opponent_moves), rng.choose(&responses)))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "15",
+        part_two: "12",
+    };
+
+    type Input = Vec<Round>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input_data)
+    }
+
+    fn part_one(instructions: &Self::Input) -> Answer {
+        calculate_score(instructions).into()
+    }
+
+    fn part_two(instructions: &Self::Input) -> Answer {
+        calculate_score_cheating(instructions).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{calculate_score, calculate_score_cheating, generate_input, parse_instructions, Outcome, Shape};
+    use aoc_core::rng::Rng;
+    use aoc_core::ParseError;
+
+    aoc_core::example_tests!(super::Day02);
+
+    #[test]
+    fn calculate_score_cheating_works() {
+        let instructions = parse_instructions("A Y\nB X\nC Z\n").unwrap();
+        let score = calculate_score_cheating(&instructions);
+
+        assert_eq!(score, 12);
+    }
+
+    #[test]
+    fn both_readings_score_all_combinations() {
+        // (line, score when Y means paper, score when Y means draw)
+        let combinations = [
+            ("A X", 4, 3),
+            ("A Y", 8, 4),
+            ("A Z", 3, 8),
+            ("B X", 1, 1),
+            ("B Y", 5, 5),
+            ("B Z", 9, 9),
+            ("C X", 7, 2),
+            ("C Y", 2, 6),
+            ("C Z", 6, 7),
+        ];
+
+        for (line, score, score_cheating) in combinations {
+            let rounds = parse_instructions(line).unwrap();
+
+            assert_eq!(calculate_score(&rounds), score, "{}", line);
+            assert_eq!(calculate_score_cheating(&rounds), score_cheating, "{}", line);
+        }
+    }
+
+    #[test]
+    fn beats_is_a_cycle() {
+        for shape in Shape::ALL {
+            assert_ne!(shape.beats(), shape);
+            assert_eq!(shape.beats().beaten_by(), shape);
+            assert_eq!(shape.outcome_against(shape.beats()), Outcome::Win);
+            assert_eq!(shape.beats().outcome_against(shape), Outcome::Lose);
+
+            for outcome in Outcome::ALL {
+                let me = Shape::for_outcome(shape, outcome);
+                assert_eq!(me.outcome_against(shape), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn shapes_and_outcomes_parse_from_codes() {
+        assert_eq!("B".parse::<Shape>(), Ok(Shape::Paper));
+        assert_eq!("Z".parse::<Shape>(), Ok(Shape::Scissors));
+        assert_eq!("X".parse::<Outcome>(), Ok(Outcome::Lose));
+        assert!("D".parse::<Shape>().is_err());
+        assert!("A".parse::<Outcome>().is_err());
+    }
+
+    #[test]
+    fn parse_instructions_reports_invalid_moves() {
+        let result = parse_instructions("A Y\nB W\n");
+
+        assert_eq!(result, Err(ParseError::new(2, 3, "W", "a response X, Y or Z")));
+    }
+
+    #[test]
+    fn parse_instructions_reports_incomplete_lines() {
+        let result = parse_instructions("A\n");
+
+        assert_eq!(result, Err(ParseError::new(1, 1, "A", "two moves like `A Y`")));
+    }
+
+    #[test]
+    fn generate_input_writes_valid_rounds() {
+        let input_data = generate_input(&mut Rng::new(2), 100);
+
+        assert_eq!(parse_instructions(&input_data).unwrap().len(), 100);
+    }
+}
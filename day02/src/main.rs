@@ -1,92 +1,235 @@
+use aoc_core::input::{self, exit_with};
+use aoc_core::Solution;
+use day02::{
+    best_response, equilibrium, mappings_with_score, opponent_distribution, rank_mappings,
+    run_tournament, trace, Bot, Day02, Game, GameError, Reading, ScoreSummary, TournamentOptions,
+};
+use std::env;
 use std::fs;
+use std::process;
 
-const OPPONENT_ROCK: &str = "A";
-const OPPONENT_PAPER: &str = "B";
-const OPPONENT_SCISSORS: &str = "C";
-
-const MY_ROCK: &str = "X";
-const MY_PAPER: &str = "Y";
-const MY_SCISSORS: &str = "Z";
-
-const LOSE: &str = "X";
-const DRAW: &str = "Y";
-const WIN: &str = "Z";
-
-const SCORE_WIN: i32 = 6;
-const SCORE_DRAW: i32 = 3;
-const SCORE_LOSE: i32 = 0;
-
-const SCORE_ROCK: i32 = 1;
-const SCORE_PAPER: i32 = 2;
-const SCORE_SCISSORS: i32 = 3;
-
-fn calculate_score_cheating(instructions: &[(&str, &str)]) -> i32 {
-    // Rock = 1
-    // Paper = 2
-    // Scissors = 3
-    instructions
-        .iter()
-        .map(|instruction| match instruction {
-            (OPPONENT_ROCK, LOSE) => SCORE_LOSE + SCORE_SCISSORS,
-            (OPPONENT_ROCK, DRAW) => SCORE_DRAW + SCORE_ROCK,
-            (OPPONENT_ROCK, WIN) => SCORE_WIN + SCORE_PAPER,
-            (OPPONENT_PAPER, LOSE) => SCORE_LOSE + SCORE_ROCK,
-            (OPPONENT_PAPER, DRAW) => SCORE_DRAW + SCORE_PAPER,
-            (OPPONENT_PAPER, WIN) => SCORE_WIN + SCORE_SCISSORS,
-            (OPPONENT_SCISSORS, LOSE) => SCORE_LOSE + SCORE_PAPER,
-            (OPPONENT_SCISSORS, DRAW) => SCORE_DRAW + SCORE_SCISSORS,
-            (OPPONENT_SCISSORS, WIN) => SCORE_WIN + SCORE_ROCK,
-            (_, _) => panic!("Invalid combination"),
-        })
-        .sum()
+/// Loads one of the built-in games by name, or a game definition from a file.
+fn load_game(name: &str) -> Game {
+    if let Some(game) = Game::built_in(name) {
+        return game;
+    }
+
+    let text = fs::read_to_string(name)
+        .unwrap_or_else(|err| exit_with(&format!("Unable to read {}: {}", name, err)));
+
+    Game::parse(&text).unwrap_or_else(|err| match err {
+        GameError::Parse(err) => exit_with(&err.with_file(name).render(&text)),
+        err => exit_with(&format!("Invalid game {}: {}", name, err)),
+    })
 }
 
-fn calculate_score(instructions: &[(&str, &str)]) -> i32 {
-    instructions
-        .iter()
-        .map(|instruction| match instruction {
-            (OPPONENT_ROCK, MY_PAPER) => SCORE_WIN + SCORE_PAPER,
-            (OPPONENT_ROCK, MY_ROCK) => SCORE_DRAW + SCORE_ROCK,
-            (OPPONENT_ROCK, MY_SCISSORS) => SCORE_LOSE + SCORE_SCISSORS,
-            (OPPONENT_PAPER, MY_ROCK) => SCORE_LOSE + SCORE_ROCK,
-            (OPPONENT_PAPER, MY_SCISSORS) => SCORE_WIN + SCORE_SCISSORS,
-            (OPPONENT_PAPER, MY_PAPER) => SCORE_WIN + SCORE_PAPER,
-            (OPPONENT_SCISSORS, MY_SCISSORS) => SCORE_DRAW + SCORE_SCISSORS,
-            (OPPONENT_SCISSORS, MY_PAPER) => SCORE_LOSE + SCORE_PAPER,
-            (OPPONENT_SCISSORS, MY_ROCK) => SCORE_WIN + SCORE_ROCK,
-            (_, _) => panic!("Invalid combination"),
-        })
-        .sum()
+/// Prints how often the opponent picks each shape, the best single shape to
+/// answer with, and the equilibrium strategy of the game.
+fn print_strategy(game: &Game) {
+    let (source, input_data) = input::read_or_exit(Day02::DAY);
+    let opponents = game
+        .parse_opponents(&input_data)
+        .unwrap_or_else(|err| exit_with(&err.with_file(&source.to_string()).render(&input_data)));
+    let distribution = opponent_distribution(game, &opponents);
+
+    println!("Opponent ({} rounds):", opponents.len());
+
+    for (shape, probability) in game.shapes().iter().zip(&distribution) {
+        println!("  {:<10} {:>6.2}%", shape.name, probability * 100.0);
+    }
+
+    let best = best_response(game, &distribution);
+
+    println!(
+        "Best response: always {} ({:.3} points per round, {:.0} in total)",
+        game.shapes()[best.shape].name,
+        best.expected_score,
+        best.expected_score * opponents.len() as f64
+    );
+
+    let equilibrium = equilibrium(game).unwrap_or_else(|err| exit_with(&err.to_string()));
+
+    println!(
+        "Equilibrium ({:.3} points per round):",
+        equilibrium.expected_score
+    );
+
+    for (shape, probability) in game.shapes().iter().zip(&equilibrium.strategy) {
+        println!("  {:<10} {:>6.2}%", shape.name, probability * 100.0);
+    }
 }
 
-fn main() {
-    let input_data = fs::read_to_string("data/input.txt").unwrap();
-    let lines = input_data.lines();
+/// Plays the built-in bots and both readings of the guide against each other.
+fn print_tournament(game: &Game, options: TournamentOptions) {
+    let (source, input_data) = input::read_or_exit(Day02::DAY);
+    let mut bots = Bot::all(game);
 
-    let instructions: Vec<(&str, &str)> = lines
-        .map(|line| {
-            let moves: Vec<&str> = line.split(' ').collect();
-            (moves[0], moves[1])
-        })
-        .collect();
+    for reading in [Reading::Shapes, Reading::Outcomes] {
+        let bot = Bot::from_guide(game, &input_data, reading).unwrap_or_else(|err| {
+            exit_with(&err.with_file(&source.to_string()).render(&input_data))
+        });
+        bots.push(bot);
+    }
 
-    let total_score: i32 = calculate_score(&instructions);
-    let total_score_cheated: i32 = calculate_score_cheating(&instructions);
+    println!(
+        "{} matches of {} rounds per pairing, seed {}",
+        options.matches, options.rounds, options.seed
+    );
+    println!(
+        "{:<20} {:<20} {:>7} {:>7} {:>7} {:>20} {:>20}",
+        "first", "second", "win", "draw", "loss", "first score", "second score"
+    );
 
-    println!("Total score: {}", total_score);
-    println!("Total score cheated: {}", total_score_cheated);
+    for pairing in run_tournament(game, &bots, options) {
+        let (wins, draws, losses) = pairing.rates();
+        let summary = |score: ScoreSummary| format!("{:.1} ± {:.1}", score.mean, score.std_dev);
+
+        println!(
+            "{:<20} {:<20} {:>6.1}% {:>6.1}% {:>6.1}% {:>20} {:>20}",
+            pairing.first,
+            pairing.second,
+            wins * 100.0,
+            draws * 100.0,
+            losses * 100.0,
+            summary(pairing.first_score),
+            summary(pairing.second_score)
+        );
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::calculate_score_cheating;
+/// The flags that each select a different thing for the binary to do.
+const MODES: [&str; 5] = [
+    "--strategy",
+    "--tournament",
+    "--cipher",
+    "--cipher-score",
+    "--trace",
+];
 
-    #[test]
-    fn calculate_score_cheating_works() {
-        let instructions = vec![("A", "Y"), ("B", "X"), ("C", "Z")];
-        let score = calculate_score_cheating(&instructions);
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
+}
+
+/// Rejects flags that would otherwise be silently ignored: a second mode,
+/// `--game` with a mode that only knows the puzzle's own game, and the
+/// tournament settings without `--tournament`.
+fn check_modes() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let given = |flag: &str| args.iter().any(|arg| arg == flag);
+    let modes: Vec<&str> = MODES.into_iter().filter(|mode| given(mode)).collect();
 
-        assert_eq!(score, 12);
+    if let [first, second, ..] = modes[..] {
+        usage_error(&format!("{} cannot be combined with {}", first, second));
+    }
+
+    if let Some(mode) = modes.first() {
+        if given("--game") && !matches!(*mode, "--strategy" | "--tournament") {
+            usage_error(&format!("--game cannot be combined with {}", mode));
+        }
+    }
+
+    for flag in ["--rounds", "--matches", "--seed"] {
+        if given(flag) && !given("--tournament") {
+            usage_error(&format!("{} can only be used with --tournament", flag));
+        }
     }
 }
 
+fn main() {
+    check_modes();
+
+    let game_name = input::flag_or_exit::<String>("--game");
+
+    if env::args().any(|arg| arg == "--tournament") {
+        let defaults = TournamentOptions::default();
+        let options = TournamentOptions {
+            rounds: input::flag_or_exit("--rounds").unwrap_or(defaults.rounds),
+            matches: input::flag_or_exit("--matches").unwrap_or(defaults.matches),
+            seed: input::flag_or_exit("--seed").unwrap_or(defaults.seed),
+        };
+
+        print_tournament(&load_game(game_name.as_deref().unwrap_or("rps")), options);
+        return;
+    }
+
+    if env::args().any(|arg| arg == "--strategy") {
+        print_strategy(&load_game(game_name.as_deref().unwrap_or("rps")));
+        return;
+    }
+
+    if let Some(name) = game_name {
+        let game = load_game(&name);
+        let (source, input_data) = input::read_or_exit(Day02::DAY);
+
+        let score = |reading| {
+            game.score_guide(&input_data, reading)
+                .unwrap_or_else(|err| {
+                    exit_with(&err.with_file(&source.to_string()).render(&input_data))
+                })
+        };
+
+        println!("Total score: {}", score(Reading::Shapes));
+        println!("Total score cheated: {}", score(Reading::Outcomes));
+        return;
+    }
+
+    let cipher_score = input::flag_or_exit::<i32>("--cipher-score");
+    let trace_reading = input::flag_or_exit::<Reading>("--trace");
+    let instructions = input::parse_or_exit::<Day02>();
+
+    if let Some(reading) = trace_reading {
+        println!(
+            "{:>5}  {:<9} {:<9} {:<7} {:>6}  {:>6}",
+            "line", "opponent", "me", "outcome", "points", "total"
+        );
+
+        for step in trace(&instructions, reading) {
+            println!(
+                "{:>5}  {:<9} {:<9} {:<7} {:>2} + {}  {:>6}",
+                step.line_number,
+                format!("{:?}", step.opponent),
+                format!("{:?}", step.shape),
+                format!("{:?}", step.outcome),
+                step.shape_points,
+                step.outcome_points,
+                step.total
+            );
+        }
+
+        return;
+    }
+
+    if env::args().any(|arg| arg == "--cipher") {
+        for (rank, ranked) in rank_mappings(&instructions).iter().enumerate() {
+            println!(
+                "{:>3}. {:<32} {}",
+                rank + 1,
+                ranked.mapping.to_string(),
+                ranked.score
+            );
+        }
+
+        return;
+    }
+
+    if let Some(score) = cipher_score {
+        let mappings = mappings_with_score(&instructions, score);
+
+        if mappings.is_empty() {
+            println!("No mapping gives a total score of {}", score);
+        }
+
+        for mapping in mappings {
+            println!("{}", mapping);
+        }
+
+        return;
+    }
+
+    let total_score = Day02::part_one(&instructions);
+    let total_score_cheated = Day02::part_two(&instructions);
+
+    println!("Total score: {}", total_score);
+    println!("Total score cheated: {}", total_score_cheated);
+}
@@ -0,0 +1,305 @@
+use crate::Game;
+use std::fmt::Display;
+
+const EPSILON: f64 = 1e-9;
+
+/// The most shapes [`equilibrium`] searches, which keeps it to a few
+/// thousand supports.
+pub const MAX_EQUILIBRIUM_SHAPES: usize = 12;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BestResponse {
+    pub shape: usize,
+    pub expected_score: f64,
+}
+
+/// A mixed strategy that is a best response to itself: when both players
+/// pick shapes with these probabilities, neither can score more by changing.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Equilibrium {
+    pub strategy: Vec<f64>,
+    pub expected_score: f64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TooManyShapes {
+    pub shapes: usize,
+}
+
+impl Display for TooManyShapes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The equilibrium can only be found for games with at most {} shapes, this one has {}",
+            MAX_EQUILIBRIUM_SHAPES, self.shapes
+        )
+    }
+}
+
+impl std::error::Error for TooManyShapes {}
+
+/// The share of rounds in which the opponent played each shape of the game.
+pub fn opponent_distribution(game: &Game, opponents: &[usize]) -> Vec<f64> {
+    let mut counts = vec![0.0; game.shapes().len()];
+
+    for opponent in opponents {
+        counts[*opponent] += 1.0;
+    }
+
+    counts
+        .iter()
+        .map(|count| count / opponents.len().max(1) as f64)
+        .collect()
+}
+
+/// The average score per round of always playing `me` against an opponent
+/// that picks shapes with the given probabilities.
+pub fn expected_score(game: &Game, distribution: &[f64], me: usize) -> f64 {
+    distribution
+        .iter()
+        .enumerate()
+        .map(|(opponent, probability)| probability * game.score(opponent, me) as f64)
+        .sum()
+}
+
+/// The single shape that scores best on average against the distribution.
+/// On ties the shape defined first in the game wins.
+pub fn best_response(game: &Game, distribution: &[f64]) -> BestResponse {
+    (0..game.shapes().len())
+        .map(|shape| BestResponse {
+            shape,
+            expected_score: expected_score(game, distribution, shape),
+        })
+        .reduce(|best, candidate| {
+            if candidate.expected_score > best.expected_score + EPSILON {
+                candidate
+            } else {
+                best
+            }
+        })
+        .unwrap()
+}
+
+/// Solves `matrix * x = rhs` with Gaussian elimination and partial pivoting,
+/// or returns `None` when the system is singular.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+
+    for column in 0..size {
+        let pivot = (column..size).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .total_cmp(&matrix[*b][column].abs())
+        })?;
+
+        if matrix[pivot][column].abs() < EPSILON {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        let pivot_row = matrix[column].clone();
+
+        for row in 0..size {
+            if row != column {
+                let factor = matrix[row][column] / pivot_row[column];
+
+                for (value, pivot_value) in matrix[row].iter_mut().zip(&pivot_row).skip(column) {
+                    *value -= factor * pivot_value;
+                }
+
+                rhs[row] -= factor * rhs[column];
+            }
+        }
+    }
+
+    Some((0..size).map(|row| rhs[row] / matrix[row][row]).collect())
+}
+
+/// Finds the strategy on a given support that makes every shape in it score
+/// the same, if that strategy is an equilibrium.
+fn equilibrium_on(game: &Game, support: &[usize]) -> Option<Equilibrium> {
+    let size = support.len();
+
+    // Unknowns are the probabilities of the shapes in the support followed
+    // by the value of the game.
+    let mut matrix = vec![vec![0.0; size + 1]; size + 1];
+    let mut rhs = vec![0.0; size + 1];
+
+    for (row, me) in support.iter().enumerate() {
+        for (column, opponent) in support.iter().enumerate() {
+            matrix[row][column] = game.score(*opponent, *me) as f64;
+        }
+
+        matrix[row][size] = -1.0;
+    }
+
+    matrix[size][..size].fill(1.0);
+    rhs[size] = 1.0;
+
+    let solution = solve(matrix, rhs)?;
+
+    if solution[..size]
+        .iter()
+        .any(|probability| *probability < -EPSILON)
+    {
+        return None;
+    }
+
+    let mut strategy = vec![0.0; game.shapes().len()];
+
+    for (shape, probability) in support.iter().zip(&solution) {
+        strategy[*shape] = probability.max(0.0);
+    }
+
+    let value = solution[size];
+    let stable = (0..game.shapes().len())
+        .all(|shape| expected_score(game, &strategy, shape) <= value + EPSILON);
+
+    stable.then_some(Equilibrium {
+        strategy,
+        expected_score: value,
+    })
+}
+
+/// Finds a symmetric mixed-strategy equilibrium of the game by trying every
+/// support, largest first. Every finite symmetric game has one, but the
+/// number of supports doubles with each shape, so games with more than
+/// [`MAX_EQUILIBRIUM_SHAPES`] shapes are refused.
+pub fn equilibrium(game: &Game) -> Result<Equilibrium, TooManyShapes> {
+    let count = game.shapes().len();
+
+    if count > MAX_EQUILIBRIUM_SHAPES {
+        return Err(TooManyShapes { shapes: count });
+    }
+
+    let masks = 1u32..1 << count;
+
+    let equilibrium = (1..=count)
+        .rev()
+        .flat_map(|size| {
+            masks
+                .clone()
+                .filter(move |mask| mask.count_ones() as usize == size)
+        })
+        .find_map(|mask| {
+            let support: Vec<usize> = (0..count)
+                .filter(|shape| mask & (1 << shape) != 0)
+                .collect();
+            equilibrium_on(game, &support)
+        })
+        .expect("every symmetric game has a symmetric equilibrium");
+
+    Ok(equilibrium)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_score, generate_input, parse_instructions, Round, Shape};
+    use aoc_core::rng::Rng;
+
+    #[test]
+    fn expected_score_agrees_with_calculate_score() {
+        let game = Game::rock_paper_scissors();
+        let input_data = generate_input(&mut Rng::new(19), 300);
+        let rounds = parse_instructions(&input_data).unwrap();
+        let distribution =
+            opponent_distribution(&game, &game.parse_opponents(&input_data).unwrap());
+
+        for (index, shape) in Shape::ALL.into_iter().enumerate() {
+            let always: Vec<Round> = rounds
+                .iter()
+                .map(|round| Round { shape, ..*round })
+                .collect();
+            let expected = expected_score(&game, &distribution, index) * rounds.len() as f64;
+
+            assert!((expected - calculate_score(&always) as f64).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn best_response_counters_the_favourite_shape() {
+        let game = Game::rock_paper_scissors();
+        let opponents = game.parse_opponents("A X\nA X\nB X\n").unwrap();
+
+        let best = best_response(&game, &opponent_distribution(&game, &opponents));
+
+        // Paper scores 8 against rock and 5 against paper.
+        assert_eq!(game.shapes()[best.shape].name, "Paper");
+        assert!((best.expected_score - 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn equilibrium_of_rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+
+        let equilibrium = equilibrium(&game).unwrap();
+
+        let expected = [4.0 / 9.0, 1.0 / 9.0, 4.0 / 9.0];
+        assert!(equilibrium
+            .strategy
+            .iter()
+            .zip(expected)
+            .all(|(p, q)| (p - q).abs() < 1e-9));
+        assert!((equilibrium.expected_score - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn equilibrium_cannot_be_improved_on() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+
+        let equilibrium = equilibrium(&game).unwrap();
+
+        assert!((equilibrium.strategy.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        for shape in 0..game.shapes().len() {
+            assert!(
+                expected_score(&game, &equilibrium.strategy, shape)
+                    <= equilibrium.expected_score + 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn equilibrium_refuses_large_games() {
+        let count = MAX_EQUILIBRIUM_SHAPES + 1;
+        let text = cyclic_game(count);
+        let game = Game::parse(&text).unwrap();
+
+        assert_eq!(equilibrium(&game), Err(TooManyShapes { shapes: count }));
+    }
+
+    #[test]
+    fn equilibrium_of_the_largest_game() {
+        let game = Game::parse(&cyclic_game(MAX_EQUILIBRIUM_SHAPES)).unwrap();
+
+        let equilibrium = equilibrium(&game).unwrap();
+
+        assert!((equilibrium.strategy.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    /// A game where every shape beats the next `count / 2` shapes. With an
+    /// even count only the first half beats the shape opposite it, so every
+    /// pair still has a single winner.
+    fn cyclic_game(count: usize) -> String {
+        let mut text = String::new();
+
+        for shape in 0..count {
+            text += &format!("shape S{} {} A{} X{}\n", shape, shape + 1, shape, shape);
+        }
+
+        for winner in 0..count {
+            for step in 1..=count / 2 {
+                if 2 * step == count && 2 * winner >= count {
+                    continue;
+                }
+
+                text += &format!("beats S{} S{}\n", winner, (winner + step) % count);
+            }
+        }
+
+        text + "outcomes L D W\n"
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{best_response, Game, Reading};
+use aoc_core::rng::Rng;
+use aoc_core::ParseError;
+use std::cmp::Ordering;
+
+/// A strategy that picks a shape each round, knowing only what both players
+/// played in the rounds before.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Bot {
+    /// Always plays the same shape.
+    Fixed(usize),
+    /// Plays every shape of the game in turn.
+    Cycle,
+    /// Plays a shape picked uniformly at random.
+    Random,
+    /// Plays the best response to the opponent's moves so far.
+    Frequency,
+    /// Plays whatever the opponent played last round.
+    CopyLast,
+    /// Replays a list of shapes, starting over when it runs out.
+    Replay { name: String, shapes: Vec<usize> },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TournamentOptions {
+    pub rounds: usize,
+    pub matches: usize,
+    pub seed: u64,
+}
+
+/// Summary of the total score a bot reached in each match of a pairing.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ScoreSummary {
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+/// The results of every match between two bots, from the first bot's side.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pairing {
+    pub first: String,
+    pub second: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub first_score: ScoreSummary,
+    pub second_score: ScoreSummary,
+}
+
+/// The shapes a player picked so far in a match, with a running count per
+/// shape so adaptive bots do not recount the whole match every round.
+struct History {
+    moves: Vec<usize>,
+    counts: Vec<usize>,
+}
+
+impl History {
+    fn new(game: &Game, rounds: usize) -> History {
+        History {
+            moves: Vec::with_capacity(rounds),
+            counts: vec![0; game.shapes().len()],
+        }
+    }
+
+    fn push(&mut self, shape: usize) {
+        self.moves.push(shape);
+        self.counts[shape] += 1;
+    }
+
+    /// The share of rounds in which each shape was played.
+    fn distribution(&self) -> Vec<f64> {
+        self.counts
+            .iter()
+            .map(|count| *count as f64 / self.moves.len().max(1) as f64)
+            .collect()
+    }
+}
+
+impl Default for TournamentOptions {
+    fn default() -> Self {
+        TournamentOptions {
+            rounds: 1000,
+            matches: 100,
+            seed: 0,
+        }
+    }
+}
+
+impl Bot {
+    /// A bot that replays our column of the strategy guide, read either as
+    /// shapes or as the outcomes the guide asked for.
+    pub fn from_guide(game: &Game, input_data: &str, reading: Reading) -> Result<Bot, ParseError> {
+        let shapes: Vec<usize> = game
+            .parse_guide(input_data, reading)?
+            .iter()
+            .map(|round| game.shape_to_play(round))
+            .collect();
+
+        let name = match reading {
+            Reading::Shapes => "guide (shapes)",
+            Reading::Outcomes => "guide (outcomes)",
+        };
+
+        Ok(Bot::Replay {
+            name: name.to_string(),
+            shapes,
+        })
+    }
+
+    /// Every fixed bot for the game followed by the adaptive ones.
+    pub fn all(game: &Game) -> Vec<Bot> {
+        let mut bots: Vec<Bot> = (0..game.shapes().len()).map(Bot::Fixed).collect();
+        bots.extend([Bot::Cycle, Bot::Random, Bot::Frequency, Bot::CopyLast]);
+        bots
+    }
+
+    pub fn name(&self, game: &Game) -> String {
+        match self {
+            Bot::Fixed(shape) => format!("always {}", game.shapes()[*shape].name),
+            Bot::Cycle => "cycle".to_string(),
+            Bot::Random => "random".to_string(),
+            Bot::Frequency => "frequency".to_string(),
+            Bot::CopyLast => "copy last".to_string(),
+            Bot::Replay { name, .. } => name.clone(),
+        }
+    }
+
+    fn play(&self, game: &Game, opponent: &History, rng: &mut Rng) -> usize {
+        let round = opponent.moves.len();
+        let count = game.shapes().len();
+
+        match self {
+            Bot::Fixed(shape) => *shape,
+            Bot::Cycle => round % count,
+            Bot::Random => rng.below(count as u64) as usize,
+            Bot::Frequency if round > 0 => best_response(game, &opponent.distribution()).shape,
+            Bot::CopyLast if round > 0 => opponent.moves[round - 1],
+            Bot::Frequency | Bot::CopyLast => rng.below(count as u64) as usize,
+            Bot::Replay { shapes, .. } if shapes.is_empty() => 0,
+            Bot::Replay { shapes, .. } => shapes[round % shapes.len()],
+        }
+    }
+}
+
+impl ScoreSummary {
+    fn from_scores(scores: &[i32]) -> ScoreSummary {
+        let count = scores.len().max(1) as f64;
+        let mean = scores.iter().map(|score| *score as f64).sum::<f64>() / count;
+        let variance = scores
+            .iter()
+            .map(|score| (*score as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        ScoreSummary {
+            min: scores.iter().copied().min().unwrap_or(0),
+            max: scores.iter().copied().max().unwrap_or(0),
+            mean,
+            std_dev: variance.sqrt(),
+        }
+    }
+}
+
+impl Pairing {
+    pub fn matches(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// The share of matches the first bot won, drew and lost.
+    pub fn rates(&self) -> (f64, f64, f64) {
+        let matches = self.matches().max(1) as f64;
+
+        (
+            self.wins as f64 / matches,
+            self.draws as f64 / matches,
+            self.losses as f64 / matches,
+        )
+    }
+}
+
+/// Plays one match and returns the total scores of both bots.
+fn play_match(game: &Game, first: &Bot, second: &Bot, rounds: usize, rng: &mut Rng) -> (i32, i32) {
+    let mut first_moves = History::new(game, rounds);
+    let mut second_moves = History::new(game, rounds);
+
+    for _ in 0..rounds {
+        let first_move = first.play(game, &second_moves, rng);
+        let second_move = second.play(game, &first_moves, rng);
+
+        first_moves.push(first_move);
+        second_moves.push(second_move);
+    }
+
+    let score = |mine: &[usize], theirs: &[usize]| -> i32 {
+        mine.iter()
+            .zip(theirs)
+            .map(|(me, opponent)| game.score(*opponent, *me))
+            .sum()
+    };
+
+    (
+        score(&first_moves.moves, &second_moves.moves),
+        score(&second_moves.moves, &first_moves.moves),
+    )
+}
+
+/// Plays every pair of bots against each other. Every pairing gets its own
+/// generator derived from the seed, so adding a bot leaves the results of
+/// the other pairings unchanged.
+pub fn run_tournament(game: &Game, bots: &[Bot], options: TournamentOptions) -> Vec<Pairing> {
+    let mut pairings = Vec::new();
+
+    for (i, first) in bots.iter().enumerate() {
+        for (j, second) in bots.iter().enumerate().skip(i + 1) {
+            let mut rng = Rng::new(options.seed ^ ((i as u64) << 32 | j as u64));
+            let mut first_scores = Vec::with_capacity(options.matches);
+            let mut second_scores = Vec::with_capacity(options.matches);
+            let (mut wins, mut draws, mut losses) = (0, 0, 0);
+
+            for _ in 0..options.matches {
+                let (first_score, second_score) =
+                    play_match(game, first, second, options.rounds, &mut rng);
+
+                match first_score.cmp(&second_score) {
+                    Ordering::Greater => wins += 1,
+                    Ordering::Equal => draws += 1,
+                    Ordering::Less => losses += 1,
+                }
+
+                first_scores.push(first_score);
+                second_scores.push(second_score);
+            }
+
+            pairings.push(Pairing {
+                first: first.name(game),
+                second: second.name(game),
+                wins,
+                draws,
+                losses,
+                first_score: ScoreSummary::from_scores(&first_scores),
+                second_score: ScoreSummary::from_scores(&second_scores),
+            });
+        }
+    }
+
+    pairings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(matches: usize, rounds: usize) -> TournamentOptions {
+        TournamentOptions {
+            rounds,
+            matches,
+            seed: 7,
+        }
+    }
+
+    #[test]
+    fn fixed_bots_score_the_same_every_match() {
+        let game = Game::rock_paper_scissors();
+
+        let pairings = run_tournament(&game, &[Bot::Fixed(0), Bot::Fixed(1)], options(5, 10));
+
+        assert_eq!(pairings.len(), 1);
+        assert_eq!(
+            (pairings[0].wins, pairings[0].draws, pairings[0].losses),
+            (0, 0, 5)
+        );
+        assert_eq!(pairings[0].first_score.mean, 10.0);
+        assert_eq!(pairings[0].second_score.mean, 80.0);
+        assert_eq!(pairings[0].second_score.std_dev, 0.0);
+    }
+
+    #[test]
+    fn frequency_bot_beats_a_fixed_bot() {
+        let game = Game::rock_paper_scissors();
+
+        let pairings = run_tournament(&game, &[Bot::Frequency, Bot::Fixed(2)], options(20, 50));
+
+        assert_eq!(pairings[0].wins, 20);
+    }
+
+    fn history(game: &Game, moves: &[usize]) -> History {
+        let mut history = History::new(game, moves.len());
+        moves.iter().for_each(|shape| history.push(*shape));
+        history
+    }
+
+    #[test]
+    fn copy_last_draws_against_a_fixed_bot_after_the_first_round() {
+        let game = Game::rock_paper_scissors();
+        let mut rng = Rng::new(1);
+
+        assert_eq!(Bot::CopyLast.play(&game, &history(&game, &[2, 1]), &mut rng), 1);
+        assert_eq!(Bot::Cycle.play(&game, &history(&game, &[0, 0, 0, 0]), &mut rng), 1);
+    }
+
+    #[test]
+    fn history_counts_match_the_distribution_of_its_moves() {
+        let game = Game::rock_paper_scissors();
+        let moves = [0, 2, 2, 1, 2];
+
+        assert_eq!(history(&game, &moves).counts, vec![1, 1, 3]);
+        assert_eq!(
+            history(&game, &moves).distribution(),
+            crate::opponent_distribution(&game, &moves)
+        );
+    }
+
+    #[test]
+    fn tournaments_are_reproducible() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let bots = Bot::all(&game);
+
+        let first = run_tournament(&game, &bots, options(10, 100));
+        let second = run_tournament(&game, &bots, options(10, 100));
+
+        assert_eq!(first.len(), bots.len() * (bots.len() - 1) / 2);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn guide_replay_scores_like_the_guide() {
+        let game = Game::rock_paper_scissors();
+        let input_data = "A Y\nB X\nC Z\n";
+        let guide = Bot::from_guide(&game, input_data, Reading::Shapes).unwrap();
+        let opponent = Bot::Replay {
+            name: "opponent".to_string(),
+            shapes: game.parse_opponents(input_data).unwrap(),
+        };
+
+        let pairings = run_tournament(&game, &[guide, opponent], options(1, 3));
+
+        assert_eq!(pairings[0].first, "guide (shapes)");
+        assert_eq!(pairings[0].first_score.mean, 15.0);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{score_round, Outcome, Reading, Round, Shape};
+
+/// How a single line of the strategy guide was read and scored.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TraceStep {
+    pub line_number: usize,
+    pub opponent: Shape,
+    pub shape: Shape,
+    pub outcome: Outcome,
+    pub shape_points: i32,
+    pub outcome_points: i32,
+    pub total: i32,
+}
+
+impl TraceStep {
+    pub fn points(&self) -> i32 {
+        self.shape_points + self.outcome_points
+    }
+}
+
+/// Scores every round under the given reading of the second column, keeping
+/// a running total. The last total is the answer for that reading.
+pub fn trace(rounds: &[Round], reading: Reading) -> Vec<TraceStep> {
+    let mut total = 0;
+
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(index, round)| {
+            let shape = match reading {
+                Reading::Shapes => round.shape,
+                Reading::Outcomes => Shape::for_outcome(round.opponent, round.outcome),
+            };
+            let outcome = shape.outcome_against(round.opponent);
+
+            total += score_round(round.opponent, shape);
+
+            TraceStep {
+                line_number: index + 1,
+                opponent: round.opponent,
+                shape,
+                outcome,
+                shape_points: shape.score(),
+                outcome_points: outcome.score(),
+                total,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_score, calculate_score_cheating, generate_input, parse_instructions};
+    use aoc_core::rng::Rng;
+
+    #[test]
+    fn trace_explains_the_example() {
+        let rounds = parse_instructions("A Y\nB X\nC Z\n").unwrap();
+
+        let steps = trace(&rounds, Reading::Shapes);
+
+        assert_eq!(
+            steps[1],
+            TraceStep {
+                line_number: 2,
+                opponent: Shape::Paper,
+                shape: Shape::Rock,
+                outcome: Outcome::Lose,
+                shape_points: 1,
+                outcome_points: 0,
+                total: 9,
+            }
+        );
+        assert_eq!(
+            steps.iter().map(TraceStep::points).collect::<Vec<_>>(),
+            [8, 1, 6]
+        );
+    }
+
+    #[test]
+    fn trace_totals_agree_with_the_scores() {
+        let rounds = parse_instructions(&generate_input(&mut Rng::new(21), 500)).unwrap();
+
+        let shapes = trace(&rounds, Reading::Shapes);
+        let outcomes = trace(&rounds, Reading::Outcomes);
+
+        assert_eq!(shapes.last().unwrap().total, calculate_score(&rounds));
+        assert_eq!(
+            outcomes.last().unwrap().total,
+            calculate_score_cheating(&rounds)
+        );
+        assert!(outcomes
+            .iter()
+            .zip(&rounds)
+            .all(|(step, round)| step.outcome == round.outcome));
+    }
+}
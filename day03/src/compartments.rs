@@ -0,0 +1,263 @@
+use crate::ItemSet;
+use std::fmt::Display;
+
+/// Maps the items that may appear in a rucksack to their priorities, which
+/// start at 1. Item sets hold priorities as bits, so a table has room for at
+/// most 63 items.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PriorityTable {
+    items: Vec<char>,
+    ascii: [u8; 128],
+    description: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PriorityTableError {
+    TooManyItems(usize),
+    DuplicateItem(char),
+}
+
+/// A rucksack that cannot be split into two compartments of known items.
+/// Positions are 0-based and counted in characters.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RucksackError {
+    OddLength { items: usize },
+    UnknownItem { position: usize, item: char },
+}
+
+impl Display for PriorityTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PriorityTableError::TooManyItems(count) => write!(
+                f,
+                "A priority table holds at most {} items, got {}",
+                PriorityTable::MAX_ITEMS,
+                count
+            ),
+            PriorityTableError::DuplicateItem(item) => {
+                write!(f, "The item {} is listed twice", item)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PriorityTableError {}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RucksackError::OddLength { items } => write!(
+                f,
+                "A rucksack with {} items cannot be split into two equal compartments",
+                items
+            ),
+            RucksackError::UnknownItem { position, item } => write!(
+                f,
+                "The item {} at position {} has no priority",
+                item, position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+impl PriorityTable {
+    pub const MAX_ITEMS: usize = 63;
+
+    /// Gives the items priorities 1, 2, 3 and so on in the order listed. The
+    /// description names the items in error messages, as in "an item a-z".
+    pub fn new(
+        items: impl IntoIterator<Item = char>,
+        description: &str,
+    ) -> Result<PriorityTable, PriorityTableError> {
+        let items: Vec<char> = items.into_iter().collect();
+
+        if items.len() > PriorityTable::MAX_ITEMS {
+            return Err(PriorityTableError::TooManyItems(items.len()));
+        }
+
+        let mut ascii = [0; 128];
+
+        for (index, item) in items.iter().enumerate() {
+            if items[..index].contains(item) {
+                return Err(PriorityTableError::DuplicateItem(*item));
+            }
+
+            if item.is_ascii() {
+                ascii[*item as usize] = index as u8 + 1;
+            }
+        }
+
+        Ok(PriorityTable {
+            items,
+            ascii,
+            description: description.to_string(),
+        })
+    }
+
+    /// The priorities from the puzzle: `a` to `z` followed by `A` to `Z`.
+    pub fn standard() -> PriorityTable {
+        PriorityTable::new(('a'..='z').chain('A'..='Z'), "a-z or A-Z").unwrap()
+    }
+
+    /// The puzzle's priorities with the digits `0` to `9` after them, so
+    /// that `0` has priority 53.
+    pub fn with_digits() -> PriorityTable {
+        let items = ('a'..='z').chain('A'..='Z').chain('0'..='9');
+        PriorityTable::new(items, "a-z, A-Z or 0-9").unwrap()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn priority(&self, item: char) -> Option<u32> {
+        let priority = if item.is_ascii() {
+            self.ascii[item as usize] as usize
+        } else {
+            self.items.iter().position(|known| *known == item)? + 1
+        };
+
+        (priority > 0).then_some(priority as u32)
+    }
+
+    pub fn item(&self, priority: u32) -> Option<char> {
+        let index = (priority as usize).checked_sub(1)?;
+        self.items.get(index).copied()
+    }
+
+    /// Collects the items into a set, failing on the first item that is not
+    /// in the table.
+    pub fn item_set(&self, items: &str) -> Result<ItemSet, RucksackError> {
+        let mut set = ItemSet::EMPTY;
+
+        for (position, item) in items.chars().enumerate() {
+            let priority = self
+                .priority(item)
+                .ok_or(RucksackError::UnknownItem { position, item })?;
+
+            set = set.with_priority(priority);
+        }
+
+        Ok(set)
+    }
+
+    /// Collects the items of both compartments of a rucksack in one pass.
+    /// Unknown items are reported before an odd number of items.
+    pub fn compartments(&self, items: &str) -> Result<(ItemSet, ItemSet), RucksackError> {
+        let count = items.chars().count();
+        let mut compartments = [ItemSet::EMPTY; 2];
+
+        for (position, item) in items.chars().enumerate() {
+            let priority = self
+                .priority(item)
+                .ok_or(RucksackError::UnknownItem { position, item })?;
+            let compartment = usize::from(position >= count / 2);
+
+            compartments[compartment] = compartments[compartment].with_priority(priority);
+        }
+
+        if !count.is_multiple_of(2) {
+            return Err(RucksackError::OddLength { items: count });
+        }
+
+        Ok((compartments[0], compartments[1]))
+    }
+}
+
+/// Splits a rucksack into its two compartments, which hold the same number
+/// of items. Lengths are measured in characters, not bytes.
+pub fn split_compartments(items: &str) -> Result<(&str, &str), RucksackError> {
+    let count = items.chars().count();
+
+    if !count.is_multiple_of(2) {
+        return Err(RucksackError::OddLength { items: count });
+    }
+
+    Ok(crate::split_line(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_table_matches_the_puzzle() {
+        let table = PriorityTable::standard();
+
+        assert_eq!(table.priority('a'), Some(1));
+        assert_eq!(table.priority('Z'), Some(52));
+        assert_eq!(table.priority('0'), None);
+        assert_eq!(table.priority('é'), None);
+        assert_eq!(table.item(27), Some('A'));
+        assert_eq!(table.item(0), None);
+    }
+
+    #[test]
+    fn digits_extend_the_standard_table() {
+        let table = PriorityTable::with_digits();
+
+        assert_eq!(table.priority('z'), Some(26));
+        assert_eq!(table.priority('0'), Some(53));
+        assert_eq!(table.priority('9'), Some(62));
+
+        let (left, right) = table.compartments("ab2c2d").unwrap();
+        let shared = left & right;
+
+        assert_eq!(shared.items(&table).collect::<String>(), "2");
+        assert_eq!(shared.priority_sum(), 55);
+    }
+
+    #[test]
+    fn tables_can_hold_other_alphabets() {
+        let table = PriorityTable::new(['α', 'β', 'γ'], "α, β or γ").unwrap();
+
+        assert_eq!(table.priority('γ'), Some(3));
+        assert_eq!(
+            table
+                .compartments("αββγ")
+                .map(|(l, r)| (l & r).priority_sum()),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn invalid_tables_are_rejected() {
+        let too_many = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .chain(['+', '-']);
+
+        assert_eq!(
+            PriorityTable::new(too_many, "too many"),
+            Err(PriorityTableError::TooManyItems(64))
+        );
+        assert_eq!(
+            PriorityTable::new(['a', 'b', 'a'], "a or b"),
+            Err(PriorityTableError::DuplicateItem('a'))
+        );
+    }
+
+    #[test]
+    fn split_compartments_counts_characters() {
+        assert_eq!(split_compartments("aébé"), Ok(("aé", "bé")));
+        assert_eq!(
+            split_compartments("abc"),
+            Err(RucksackError::OddLength { items: 3 })
+        );
+    }
+
+    #[test]
+    fn unknown_items_are_reported_by_position() {
+        let table = PriorityTable::standard();
+
+        assert_eq!(
+            table.compartments("abéa"),
+            Err(RucksackError::UnknownItem {
+                position: 2,
+                item: 'é'
+            })
+        );
+    }
+}
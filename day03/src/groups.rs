@@ -0,0 +1,344 @@
+use crate::{rucksack_error, ItemSet, PriorityTable, RucksackError};
+use aoc_core::ParseError;
+use std::fmt::Display;
+
+/// The number of elves in a group in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+/// A group of elves and the items every one of them carries. The group has
+/// a badge only when exactly one such item exists.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Group {
+    pub number: usize,
+    pub first_line: usize,
+    pub last_line: usize,
+    pub candidates: ItemSet,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GroupError {
+    EmptyGroups,
+    IncompleteGroup {
+        number: usize,
+        first_line: usize,
+        rucksacks: usize,
+        group_size: usize,
+    },
+    InvalidRucksack {
+        line_number: usize,
+        error: RucksackError,
+    },
+}
+
+impl Display for GroupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupError::EmptyGroups => write!(f, "The group size must be at least 1"),
+            GroupError::IncompleteGroup {
+                number,
+                first_line,
+                rucksacks,
+                group_size,
+            } => write!(
+                f,
+                "Group {} starting at line {} has {} rucksacks, expected {}",
+                number, first_line, rucksacks, group_size
+            ),
+            GroupError::InvalidRucksack { line_number, error } => {
+                write!(f, "Line {}: {}", line_number, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GroupError {}
+
+impl Group {
+    pub fn badge(&self, table: &PriorityTable) -> Option<char> {
+        if self.candidates.len() == 1 {
+            self.candidates.first(table)
+        } else {
+            None
+        }
+    }
+}
+
+/// Splits the rucksacks into groups of `group_size` elves. Fails when the
+/// last group is short of rucksacks or a rucksack holds an unknown item.
+pub fn group_rucksacks(lines: &[String], group_size: usize) -> Result<Vec<Group>, GroupError> {
+    group_rucksacks_with(lines, group_size, &PriorityTable::standard())
+}
+
+pub fn group_rucksacks_with(
+    lines: &[String],
+    group_size: usize,
+    table: &PriorityTable,
+) -> Result<Vec<Group>, GroupError> {
+    if group_size == 0 {
+        return Err(GroupError::EmptyGroups);
+    }
+
+    lines
+        .chunks(group_size)
+        .enumerate()
+        .map(|(index, group)| {
+            let first_line = index * group_size + 1;
+
+            if group.len() < group_size {
+                return Err(GroupError::IncompleteGroup {
+                    number: index + 1,
+                    first_line,
+                    rucksacks: group.len(),
+                    group_size,
+                });
+            }
+
+            let sets = group
+                .iter()
+                .enumerate()
+                .map(|(offset, line)| {
+                    table
+                        .item_set(line.trim())
+                        .map_err(|error| GroupError::InvalidRucksack {
+                            line_number: first_line + offset,
+                            error,
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let candidates = sets
+                .into_iter()
+                .reduce(|common, items| common & items)
+                .unwrap_or_default();
+
+            Ok(Group {
+                number: index + 1,
+                first_line,
+                last_line: first_line + group_size - 1,
+                candidates,
+            })
+        })
+        .collect()
+}
+
+/// The groups that have no badge or more than one candidate for it.
+pub fn groups_without_badge(groups: &[Group]) -> Vec<Group> {
+    groups
+        .iter()
+        .filter(|group| group.candidates.len() != 1)
+        .copied()
+        .collect()
+}
+
+/// Sums the priorities of the badges, or returns `None` when a group does
+/// not have exactly one badge.
+pub fn sum_badge_priorities(groups: &[Group]) -> Option<u64> {
+    groups
+        .iter()
+        .map(|group| {
+            let badge = group.candidates;
+            (badge.len() == 1).then(|| u64::from(badge.priority_sum()))
+        })
+        .sum()
+}
+
+/// Checks that the rucksacks form complete groups of `GROUP_SIZE` with
+/// exactly one badge each, so that part two can be solved.
+pub fn check_groups(lines: &[String], table: &PriorityTable) -> Result<(), ParseError> {
+    let groups = match group_rucksacks_with(lines, GROUP_SIZE, table) {
+        Ok(groups) => groups,
+        Err(GroupError::IncompleteGroup {
+            number,
+            rucksacks,
+            group_size,
+            ..
+        }) => {
+            let last_line = &lines[lines.len() - 1];
+
+            return Err(ParseError::in_line(
+                lines.len(),
+                last_line,
+                last_line,
+                &match group_size - rucksacks {
+                    1 => format!(
+                        "another rucksack after this one to complete group {}",
+                        number
+                    ),
+                    missing => format!(
+                        "{} more rucksacks after this one to complete group {}",
+                        missing, number
+                    ),
+                },
+            ));
+        }
+        Err(GroupError::InvalidRucksack { line_number, error }) => {
+            return Err(rucksack_error(
+                line_number,
+                &lines[line_number - 1],
+                error,
+                table,
+            ));
+        }
+        Err(GroupError::EmptyGroups) => unreachable!("GROUP_SIZE is not zero"),
+    };
+
+    match groups_without_badge(&groups).first() {
+        Some(group) => {
+            let first_line = &lines[group.first_line - 1];
+            let candidates: Vec<String> = group.candidates.items(table).map(String::from).collect();
+
+            Err(ParseError::in_line(
+                group.first_line,
+                first_line,
+                first_line,
+                &format!(
+                    "a group sharing exactly one badge (lines {}-{} share {})",
+                    group.first_line,
+                    group.last_line,
+                    if candidates.is_empty() {
+                        "nothing".to_string()
+                    } else {
+                        candidates.join(", ")
+                    }
+                ),
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rucksacks;
+
+    #[test]
+    fn groups_can_have_any_size() {
+        let lines = rucksacks(&["abc", "bcd", "cde", "xaY", "Yb", "Yc"]);
+
+        let pairs = group_rucksacks(&lines, 2).unwrap();
+        let triples = group_rucksacks(&lines, 3).unwrap();
+
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[1].first_line, 3);
+        assert_eq!(pairs[1].last_line, 4);
+        assert_eq!(sum_badge_priorities(&triples), Some(3 + 51));
+    }
+
+    #[test]
+    fn incomplete_trailing_group_is_an_error() {
+        let lines = rucksacks(&["abc", "bcd", "cde", "xaY", "Yb"]);
+
+        let result = group_rucksacks(&lines, 3);
+
+        assert_eq!(
+            result,
+            Err(GroupError::IncompleteGroup {
+                number: 2,
+                first_line: 4,
+                rucksacks: 2,
+                group_size: 3,
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Group 2 starting at line 4 has 2 rucksacks, expected 3"
+        );
+        assert_eq!(group_rucksacks(&lines, 0), Err(GroupError::EmptyGroups));
+    }
+
+    #[test]
+    fn groups_without_exactly_one_badge_are_reported() {
+        let lines = rucksacks(&["ab", "ab", "ab", "ab", "cd", "ef", "ac", "cb", "cZ"]);
+
+        let table = PriorityTable::standard();
+        let groups = group_rucksacks(&lines, 3).unwrap();
+        let problems = groups_without_badge(&groups);
+
+        assert_eq!(
+            problems
+                .iter()
+                .map(|group| group.number)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(
+            problems[0].candidates.items(&table).collect::<String>(),
+            "ab"
+        );
+        assert!(problems[1].candidates.is_empty());
+        assert_eq!(groups[2].badge(&table), Some('c'));
+        assert_eq!(sum_badge_priorities(&groups), None);
+    }
+
+    #[test]
+    fn badges_are_named_by_the_table() {
+        let table = PriorityTable::with_digits();
+        let lines = rucksacks(&["a1", "b1", "c1"]);
+
+        let groups = group_rucksacks_with(&lines, 3, &table).unwrap();
+
+        assert_eq!(groups[0].badge(&table), Some('1'));
+        assert_eq!(groups_without_badge(&groups), []);
+        assert_eq!(sum_badge_priorities(&groups), Some(54));
+        assert_eq!(check_groups(&lines, &table), Ok(()));
+    }
+
+    #[test]
+    fn unknown_items_are_reported_by_line() {
+        let lines = rucksacks(&["ab", "bc", "b1"]);
+
+        let result = group_rucksacks(&lines, 3);
+
+        assert_eq!(
+            result,
+            Err(GroupError::InvalidRucksack {
+                line_number: 3,
+                error: RucksackError::UnknownItem {
+                    position: 1,
+                    item: '1'
+                }
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Line 3: The item 1 at position 1 has no priority"
+        );
+        assert_eq!(
+            check_groups(&lines, &PriorityTable::standard()),
+            Err(ParseError::new(3, 2, "1", "an item a-z or A-Z"))
+        );
+    }
+
+    #[test]
+    fn check_groups_reports_the_group_that_cannot_be_solved() {
+        let table = PriorityTable::standard();
+        let incomplete = rucksacks(&["abc", "bcd", "cde", "xaY", "Yb"]);
+        let ambiguous = rucksacks(&["abc", "bcd", "cde", "ab", "ab", "ab"]);
+
+        assert_eq!(
+            check_groups(&incomplete, &table),
+            Err(ParseError::new(
+                5,
+                1,
+                "Yb",
+                "another rucksack after this one to complete group 2"
+            ))
+        );
+        assert_eq!(
+            check_groups(&ambiguous, &table),
+            Err(ParseError::new(
+                4,
+                1,
+                "ab",
+                "a group sharing exactly one badge (lines 4-6 share a, b)"
+            ))
+        );
+        assert_eq!(
+            check_groups(&incomplete[..4], &table)
+                .unwrap_err()
+                .to_string(),
+            "4:1: expected 2 more rucksacks after this one to complete group 2, found `xaY`"
+        );
+        assert_eq!(check_groups(&incomplete[..3], &table), Ok(()));
+    }
+}
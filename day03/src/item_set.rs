@@ -0,0 +1,133 @@
+use crate::PriorityTable;
+use std::fmt::Display;
+use std::ops::BitAnd;
+
+/// A set of rucksack items stored as a bitmask, where bit `n` stands for the
+/// item with priority `n`. Bit 0 is never set.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub struct ItemSet(u64);
+
+/// An item that is not listed in the priority table.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UnknownItem(pub char);
+
+impl Display for UnknownItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The item {} has no priority", self.0)
+    }
+}
+
+impl std::error::Error for UnknownItem {}
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn with(self, item: char, table: &PriorityTable) -> Result<ItemSet, UnknownItem> {
+        let priority = table.priority(item).ok_or(UnknownItem(item))?;
+        Ok(self.with_priority(priority))
+    }
+
+    /// Adds the item with the given priority, which must be below 64.
+    pub fn with_priority(self, priority: u32) -> ItemSet {
+        ItemSet(self.0 | 1 << priority)
+    }
+
+    pub fn contains(self, item: char, table: &PriorityTable) -> bool {
+        table
+            .priority(item)
+            .is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the items in the set, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+
+            let priority = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(priority)
+        })
+    }
+
+    /// The items in the set as named by the table, lowest priority first.
+    pub fn items(self, table: &PriorityTable) -> impl Iterator<Item = char> + '_ {
+        self.priorities()
+            .filter_map(|priority| table.item(priority))
+    }
+
+    /// The item with the lowest priority, if any.
+    pub fn first(self, table: &PriorityTable) -> Option<char> {
+        self.items(table).next()
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        self.intersection(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_keeps_the_shared_items() {
+        let table = PriorityTable::standard();
+        let left = table.item_set("vJrwpWtwJgWr").unwrap();
+        let right = table.item_set("hcsFMMfFFhFp").unwrap();
+
+        let shared = left & right;
+
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared.first(&table), Some('p'));
+        assert!(shared.contains('p', &table));
+        assert!(!shared.contains('v', &table));
+    }
+
+    #[test]
+    fn sets_count_each_item_once() {
+        let table = PriorityTable::standard();
+        let set = table.item_set("aaZZb").unwrap();
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.items(&table).collect::<String>(), "abZ");
+        assert_eq!(set.priority_sum(), 1 + 2 + 52);
+        assert!(ItemSet::EMPTY.is_empty());
+    }
+
+    #[test]
+    fn items_are_named_by_the_table() {
+        let digits = PriorityTable::with_digits();
+        let set = ItemSet::EMPTY.with('7', &digits).unwrap();
+
+        assert_eq!(set.first(&digits), Some('7'));
+        assert_eq!(set.first(&PriorityTable::standard()), None);
+        assert_eq!(
+            ItemSet::EMPTY.with('7', &PriorityTable::standard()),
+            Err(UnknownItem('7'))
+        );
+    }
+}
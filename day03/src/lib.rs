@@ -0,0 +1,438 @@
+mod compartments;
+mod groups;
+mod item_set;
+mod sharing;
+
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Part, Solution};
+
+pub use compartments::{split_compartments, PriorityTable, PriorityTableError, RucksackError};
+pub use groups::{
+    check_groups, group_rucksacks, group_rucksacks_with, groups_without_badge,
+    sum_badge_priorities, Group, GroupError, GROUP_SIZE,
+};
+pub use item_set::{ItemSet, UnknownItem};
+pub use sharing::{
+    check_rucksacks, check_sharing, group_findings, rucksack_findings, shared_items, Finding, Mode,
+    Sharing, SharingError, SharingSummary, Subject,
+};
+
+/// Builds parsed rucksacks from literal lines, for tests.
+#[cfg(test)]
+pub(crate) fn rucksacks(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+/// Splits the line halfway, counting characters rather than bytes. Use
+/// `split_compartments` to reject lines with an odd number of items.
+pub fn split_line(line: &str) -> (&str, &str) {
+    let middle = line
+        .char_indices()
+        .nth(line.chars().count() / 2)
+        .map_or(line.len(), |(index, _)| index);
+
+    line.split_at(middle)
+}
+
+/// Sums the priorities of the item found in both compartments of each
+/// rucksack, or returns `None` when a rucksack does not share exactly one
+/// item.
+pub fn sum_shared_priorities(lines: &[String]) -> Option<u64> {
+    sum_shared_priorities_with(lines, &PriorityTable::standard())
+}
+
+pub fn sum_shared_priorities_with(lines: &[String], table: &PriorityTable) -> Option<u64> {
+    lines
+        .iter()
+        .map(|line| {
+            let (left, right) = table
+                .compartments(line)
+                .unwrap_or_else(|err| panic!("{}", err));
+            let shared = left & right;
+
+            (shared.len() == 1).then(|| u64::from(shared.priority_sum()))
+        })
+        .sum()
+}
+
+pub fn parse_rucksacks(input_data: &str) -> Result<Vec<String>, ParseError> {
+    parse_rucksacks_with(input_data, &PriorityTable::standard())
+}
+
+/// Reads the rucksacks, checking that every item is in the table and that
+/// every rucksack splits into two equal compartments.
+pub fn parse_rucksacks_with(
+    input_data: &str,
+    table: &PriorityTable,
+) -> Result<Vec<String>, ParseError> {
+    input_data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let items = line.trim();
+
+            match table.compartments(items) {
+                Ok(_) => Ok(items.to_string()),
+                Err(err) => Err(rucksack_error(index + 1, line, err, table)),
+            }
+        })
+        .collect()
+}
+
+/// Points at the part of the line that the table could not read.
+pub(crate) fn rucksack_error(
+    line_number: usize,
+    line: &str,
+    error: RucksackError,
+    table: &PriorityTable,
+) -> ParseError {
+    let items = line.trim();
+
+    match error {
+        RucksackError::UnknownItem { position, item } => {
+            let (start, _) = items.char_indices().nth(position).unwrap();
+
+            ParseError::in_line(
+                line_number,
+                line,
+                &items[start..start + item.len_utf8()],
+                &format!("an item {}", table.description()),
+            )
+        }
+        RucksackError::OddLength { .. } => {
+            ParseError::in_line(line_number, line, items, "an even number of items")
+        }
+    }
+}
+
+/// Fills a rucksack from its own pool of items plus the group badge, so that
+/// both compartments share exactly one item.
+fn generate_rucksack(rng: &mut Rng, pool: &[char], badge: char) -> String {
+    let mut candidates: Vec<char> = pool.iter().copied().chain([badge]).collect();
+    rng.shuffle(&mut candidates);
+
+    let shared_item = candidates[0];
+    let (left_pool, right_pool) = candidates[1..].split_at(candidates.len() / 2);
+    let length = rng.between(2, 16);
+
+    let mut fill_compartment = |own_pool: &[char]| {
+        let mut items = vec![shared_item];
+
+        if own_pool.contains(&badge) {
+            items.push(badge);
+        }
+
+        while items.len() < length {
+            items.push(*rng.choose(own_pool));
+        }
+
+        rng.shuffle(&mut items);
+        items.into_iter().collect::<String>()
+    };
+
+    let left = fill_compartment(left_pool);
+    let right = fill_compartment(right_pool);
+
+    left + &right
+}
+
+/// Writes at least `rucksacks` rucksacks, rounded up to whole groups of three.
+/// Every rucksack has exactly one item in both compartments and every group
+/// has exactly one badge.
+pub fn generate_input(rng: &mut Rng, rucksacks: usize) -> String {
+    let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let groups = rucksacks.max(1).div_ceil(3);
+    let mut lines = Vec::new();
+
+    for _ in 0..groups {
+        let badge = *rng.choose(&items);
+        let mut others: Vec<char> = items
+            .iter()
+            .copied()
+            .filter(|item| *item != badge)
+            .collect();
+        rng.shuffle(&mut others);
+
+        // Each member of the group gets a disjoint pool, so the badge is the
+        // only item the three of them have in common.
+        for pool in others.chunks(others.len() / 3) {
+            lines.push(generate_rucksack(rng, pool, badge) + "\n");
+        }
+    }
+
+    lines.concat()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "157",
+        part_two: "70",
+    };
+
+    type Input = Vec<String>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_rucksacks(input_data)
+    }
+
+    fn part_one(lines: &Self::Input) -> Answer {
+        sum_shared_priorities(lines)
+            .expect("rucksacks are checked before part one")
+            .into()
+    }
+
+    fn part_two(lines: &Self::Input) -> Answer {
+        let groups =
+            group_rucksacks(lines, GROUP_SIZE).expect("groups are checked before part two");
+
+        sum_badge_priorities(&groups)
+            .expect("badges are checked before part two")
+            .into()
+    }
+
+    /// Part one needs every rucksack to share exactly one item between its
+    /// compartments, part two needs complete groups with one badge each.
+    fn check(lines: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::One => check_rucksacks(lines),
+            Part::Two => check_groups(lines, &PriorityTable::standard()),
+        }
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_input, group_rucksacks, parse_rucksacks, parse_rucksacks_with,
+        sum_badge_priorities, sum_shared_priorities, sum_shared_priorities_with, Day03,
+        PriorityTable,
+    };
+    use aoc_core::rng::Rng;
+    use aoc_core::{Answer, ParseError, Part, Puzzle, Solution};
+    use std::collections::{HashMap, HashSet};
+
+    use super::split_line;
+
+    aoc_core::example_tests!(super::Day03);
+
+    // The original hash-based solution, kept to check the item sets against.
+
+    fn create_item_priority_scores() -> HashMap<char, i32> {
+        let item_priorities = ('a'..='z').chain('A'..='Z');
+
+        HashMap::from_iter(item_priorities.enumerate().map(|(index, item)| {
+            let score: i32 = i32::try_from(index).unwrap() + 1;
+            (item, score)
+        }))
+    }
+
+    /// The one item found in all of the given lists of items. Returns `None`
+    /// when they share no items or more than one.
+    fn get_shared_item(items: &[&str]) -> Option<char> {
+        let sets = items
+            .iter()
+            .map(|item| item.chars().collect::<HashSet<_>>());
+        let overlaps = sets.reduce(|set1, set2| set1.intersection(&set2).copied().collect())?;
+
+        if overlaps.len() == 1 {
+            overlaps.into_iter().next()
+        } else {
+            None
+        }
+    }
+
+    fn calculate_part_one(lines: &[String], item_scores: &HashMap<char, i32>) -> i32 {
+        lines
+            .iter()
+            .map(|line| split_line(line))
+            .map(|(left, right)| item_scores[&get_shared_item(&[left, right]).unwrap()])
+            .sum()
+    }
+
+    fn calculate_part_two(lines: &[String], item_scores: &HashMap<char, i32>) -> i32 {
+        let mut i = 0;
+        let mut badges: Vec<char> = Vec::new();
+
+        let inputs = lines.iter().map(|line| line.trim()).collect::<Vec<_>>();
+
+        while i < inputs.len() {
+            let group_lines = &inputs[i..i + 3];
+            let group_badge = get_shared_item(group_lines);
+
+            badges.push(group_badge.unwrap());
+
+            i += 3;
+        }
+
+        badges.iter().map(|badge| item_scores[badge]).sum()
+    }
+
+    #[test]
+    fn split_line_returns_two_halves() {
+        let line = "aabbccdd";
+        let (left, right) = split_line(line);
+
+        assert_eq!(left, "aabb");
+        assert_eq!(right, "ccdd");
+    }
+
+    #[test]
+    fn get_shared_item_returns_the_right_item() {
+        let left = "vJrwpWtwJgWr";
+        let right = "hcsFMMfFFhFp";
+
+        let shared_item = get_shared_item(&[left, right]).unwrap();
+
+        assert_eq!(shared_item, 'p');
+    }
+
+    #[test]
+    fn get_shared_item_rejects_ambiguous_items() {
+        assert_eq!(get_shared_item(&["abc", "cab"]), None);
+        assert_eq!(get_shared_item(&["abc", "xyz"]), None);
+    }
+
+    #[test]
+    fn score_items_return_the_right_score() {
+        let scores = create_item_priority_scores();
+
+        let score_a = scores[&'a'];
+        let score_b = scores[&'A'];
+
+        assert_eq!(score_a, 1);
+        assert_eq!(score_b, 27);
+    }
+
+    #[test]
+    fn create_item_priority_scores_returns_the_right_items() {
+        let scores = create_item_priority_scores();
+
+        assert_eq!(scores.keys().len(), 52);
+    }
+
+    #[test]
+    fn parse_rucksacks_reports_invalid_items() {
+        let result = parse_rucksacks("vJrwpWtwJgWr\nhcs4MMfFFhFp\n");
+
+        assert_eq!(
+            result,
+            Err(ParseError::new(2, 4, "4", "an item a-z or A-Z"))
+        );
+    }
+
+    #[test]
+    fn parse_rucksacks_reports_odd_and_non_ascii_lines() {
+        assert_eq!(
+            parse_rucksacks("abcab\n"),
+            Err(ParseError::new(1, 1, "abcab", "an even number of items"))
+        );
+        assert_eq!(
+            parse_rucksacks("abéa\n"),
+            Err(ParseError::new(1, 3, "é", "an item a-z or A-Z"))
+        );
+    }
+
+    #[test]
+    fn parse_rucksacks_with_digits() {
+        let table = PriorityTable::with_digits();
+        let rucksacks = parse_rucksacks_with("a1b1\nx9Yx\n", &table).unwrap();
+
+        assert_eq!(
+            sum_shared_priorities_with(&rucksacks, &table),
+            Some(54 + 24)
+        );
+        assert!(parse_rucksacks("a1b1\n").is_err());
+    }
+
+    #[test]
+    fn rucksacks_without_exactly_one_shared_item_fail_part_one() {
+        let lines = Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabcd\nabcd\n").unwrap();
+
+        assert_eq!(
+            Day03::check(&lines, Part::One),
+            Err(ParseError::new(
+                2,
+                1,
+                "abcd",
+                "a rucksack whose compartments share exactly one item (they share nothing)"
+            ))
+        );
+        assert_eq!(
+            sum_shared_priorities(&parse_rucksacks("abab\n").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn part_one_does_not_need_complete_groups() {
+        let puzzle: &dyn Puzzle = &Day03;
+        let one = "vJrwpWtwJgWrhcsFMMfFFhFp\n";
+        let four = include_str!("../data/example.txt")
+            .lines()
+            .take(4)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            puzzle.solve(one, &[Part::One]).unwrap()[0].answer,
+            Answer::Number(16)
+        );
+        assert_eq!(
+            puzzle.solve(&four, &[Part::One]).unwrap()[0].answer,
+            Answer::Number(16 + 38 + 42 + 22)
+        );
+        assert_eq!(puzzle.solve(&four, &Part::ALL).unwrap_err().line, 4);
+    }
+
+    #[test]
+    fn split_line_counts_characters() {
+        assert_eq!(split_line("aébé"), ("aé", "bé"));
+    }
+
+    #[test]
+    fn generate_input_plants_exactly_one_shared_item() {
+        let rucksacks = parse_rucksacks(&generate_input(&mut Rng::new(3), 100)).unwrap();
+        let item_set = |items: &str| items.chars().collect::<HashSet<_>>();
+
+        assert_eq!(rucksacks.len(), 102);
+
+        for rucksack in &rucksacks {
+            let (left, right) = split_line(rucksack);
+            assert_eq!(item_set(left).intersection(&item_set(right)).count(), 1);
+        }
+
+        for group in rucksacks.chunks(3) {
+            let common: HashSet<char> = item_set(&group[0])
+                .intersection(&item_set(&group[1]))
+                .copied()
+                .collect();
+            assert_eq!(common.intersection(&item_set(&group[2])).count(), 1);
+        }
+    }
+
+    #[test]
+    fn item_sets_agree_with_hash_sets() {
+        let scores = create_item_priority_scores();
+
+        for seed in 0..20 {
+            let rucksacks = parse_rucksacks(&generate_input(&mut Rng::new(seed), 300)).unwrap();
+
+            assert_eq!(
+                sum_shared_priorities(&rucksacks),
+                Some(calculate_part_one(&rucksacks, &scores) as u64)
+            );
+            assert_eq!(
+                sum_badge_priorities(&group_rucksacks(&rucksacks, 3).unwrap()),
+                Some(calculate_part_two(&rucksacks, &scores) as u64)
+            );
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{rucksack_error, Group, ItemSet, PriorityTable, RucksackError};
+use aoc_core::ParseError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// How many items a rucksack's compartments, or a group's rucksacks, have
+/// in common.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Sharing {
+    ExactlyOne(char),
+    None,
+    Ambiguous(Vec<char>),
+}
+
+/// Whether checking stops at the first rucksack or group that does not share
+/// exactly one item, or collects all of them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Strict,
+    Lenient,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Subject {
+    Rucksack,
+    Group(usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Finding {
+    pub subject: Subject,
+    pub line_number: usize,
+    pub sharing: Sharing,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SharingSummary {
+    pub exactly_one: usize,
+    pub problems: Vec<Finding>,
+}
+
+/// The first finding that failed a strict check.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SharingError(pub Finding);
+
+impl Sharing {
+    /// Names the shared items using the table they were read with.
+    pub fn of(shared: ItemSet, table: &PriorityTable) -> Sharing {
+        let mut items: Vec<char> = shared.items(table).collect();
+
+        match items.len() {
+            0 => Sharing::None,
+            1 => Sharing::ExactlyOne(items.pop().unwrap()),
+            _ => Sharing::Ambiguous(items),
+        }
+    }
+
+    pub fn item(&self) -> Option<char> {
+        match self {
+            Sharing::ExactlyOne(item) => Some(*item),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "strict" => Ok(Mode::Strict),
+            "lenient" => Ok(Mode::Lenient),
+            _ => Err(format!("Unknown mode {}, expected strict or lenient", text)),
+        }
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.subject {
+            Subject::Rucksack => write!(f, "Rucksack on line {}", self.line_number)?,
+            Subject::Group(number) => {
+                write!(f, "Group {} starting at line {}", number, self.line_number)?
+            }
+        }
+
+        match &self.sharing {
+            Sharing::ExactlyOne(item) => write!(f, " shares exactly one item: {}", item),
+            Sharing::None => write!(f, " shares no items"),
+            Sharing::Ambiguous(items) => {
+                write!(f, " shares {} items: {}", items.len(), join_items(items))
+            }
+        }
+    }
+}
+
+impl Display for SharingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SharingError {}
+
+impl SharingSummary {
+    pub fn none(&self) -> usize {
+        self.count(|sharing| *sharing == Sharing::None)
+    }
+
+    pub fn ambiguous(&self) -> usize {
+        self.count(|sharing| matches!(sharing, Sharing::Ambiguous(_)))
+    }
+
+    fn count(&self, predicate: impl Fn(&Sharing) -> bool) -> usize {
+        self.problems
+            .iter()
+            .filter(|finding| predicate(&finding.sharing))
+            .count()
+    }
+}
+
+fn join_items(items: &[char]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// All items found in every one of the given lists of items. Fails on the
+/// first item that is not in the table.
+pub fn shared_items(items: &[&str], table: &PriorityTable) -> Result<ItemSet, RucksackError> {
+    let sets = items
+        .iter()
+        .map(|items| table.item_set(items))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(sets
+        .into_iter()
+        .reduce(|common, items| common & items)
+        .unwrap_or_default())
+}
+
+/// Classifies each rucksack by the items its two compartments share. Fails
+/// like `parse_rucksacks_with` on a rucksack the table cannot read.
+pub fn rucksack_findings(
+    lines: &[String],
+    table: &PriorityTable,
+) -> Result<Vec<Finding>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let (left, right) = table
+                .compartments(line)
+                .map_err(|err| rucksack_error(index + 1, line, err, table))?;
+
+            Ok(Finding {
+                subject: Subject::Rucksack,
+                line_number: index + 1,
+                sharing: Sharing::of(left & right, table),
+            })
+        })
+        .collect()
+}
+
+/// Classifies each group by the items all of its rucksacks share.
+pub fn group_findings<'a>(
+    groups: &'a [Group],
+    table: &'a PriorityTable,
+) -> impl Iterator<Item = Finding> + 'a {
+    groups.iter().map(move |group| Finding {
+        subject: Subject::Group(group.number),
+        line_number: group.first_line,
+        sharing: Sharing::of(group.candidates, table),
+    })
+}
+
+/// Sorts findings into those that share exactly one item and the problems.
+/// In strict mode the first problem is returned as an error instead.
+pub fn check_sharing(
+    findings: impl IntoIterator<Item = Finding>,
+    mode: Mode,
+) -> Result<SharingSummary, SharingError> {
+    let mut summary = SharingSummary::default();
+
+    for finding in findings {
+        match (&finding.sharing, mode) {
+            (Sharing::ExactlyOne(_), _) => summary.exactly_one += 1,
+            (_, Mode::Strict) => return Err(SharingError(finding)),
+            (_, Mode::Lenient) => summary.problems.push(finding),
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Checks that the compartments of every rucksack share exactly one item,
+/// so that part one can be solved.
+pub fn check_rucksacks(lines: &[String]) -> Result<(), ParseError> {
+    let findings = rucksack_findings(lines, &PriorityTable::standard())?;
+
+    match check_sharing(findings, Mode::Strict) {
+        Ok(_) => Ok(()),
+        Err(SharingError(finding)) => {
+            let line = &lines[finding.line_number - 1];
+            let shared = match &finding.sharing {
+                Sharing::Ambiguous(items) => join_items(items),
+                _ => "nothing".to_string(),
+            };
+
+            Err(ParseError::in_line(
+                finding.line_number,
+                line,
+                line,
+                &format!(
+                    "a rucksack whose compartments share exactly one item (they share {})",
+                    shared
+                ),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{group_rucksacks, group_rucksacks_with, rucksacks};
+
+    #[test]
+    fn shared_items_returns_every_common_item() {
+        let table = PriorityTable::standard();
+        let shared = shared_items(&["abcD", "bDxy", "yDbq"], &table).unwrap();
+
+        assert_eq!(shared.items(&table).collect::<String>(), "bD");
+        assert_eq!(
+            Sharing::of(shared, &table),
+            Sharing::Ambiguous(vec!['b', 'D'])
+        );
+        assert_eq!(
+            Sharing::of(shared_items(&["ab", "cd"], &table).unwrap(), &table),
+            Sharing::None
+        );
+        assert_eq!(
+            Sharing::of(shared_items(&["ab", "bc"], &table).unwrap(), &table).item(),
+            Some('b')
+        );
+        assert_eq!(
+            shared_items(&["ab", "b4"], &table),
+            Err(RucksackError::UnknownItem {
+                position: 1,
+                item: '4'
+            })
+        );
+    }
+
+    #[test]
+    fn strict_mode_stops_at_the_first_problem() {
+        let lines = rucksacks(&["abca", "abcd", "abab"]);
+
+        let findings = rucksack_findings(&lines, &PriorityTable::standard()).unwrap();
+        let result = check_sharing(findings, Mode::Strict);
+
+        let error = result.unwrap_err();
+        assert_eq!(error.0.line_number, 2);
+        assert_eq!(error.to_string(), "Rucksack on line 2 shares no items");
+        assert_eq!(check_rucksacks(&lines[..1]), Ok(()));
+        assert_eq!(
+            check_rucksacks(&lines[1..]),
+            Err(ParseError::new(
+                1,
+                1,
+                "abcd",
+                "a rucksack whose compartments share exactly one item (they share nothing)"
+            ))
+        );
+        assert_eq!(
+            check_rucksacks(&lines[2..]).unwrap_err().to_string(),
+            "1:1: expected a rucksack whose compartments share exactly one item \
+             (they share a, b), found `abab`"
+        );
+    }
+
+    #[test]
+    fn findings_report_rucksacks_the_table_cannot_read() {
+        let lines = rucksacks(&["abca", "ab4a", "abc"]);
+        let table = PriorityTable::standard();
+
+        assert_eq!(
+            rucksack_findings(&lines, &table),
+            Err(ParseError::new(2, 3, "4", "an item a-z or A-Z"))
+        );
+        assert_eq!(
+            rucksack_findings(&lines[2..], &table),
+            Err(ParseError::new(1, 1, "abc", "an even number of items"))
+        );
+    }
+
+    #[test]
+    fn lenient_mode_reports_every_problem() {
+        let lines = rucksacks(&["abca", "abcd", "abab"]);
+
+        let table = PriorityTable::standard();
+        let summary =
+            check_sharing(rucksack_findings(&lines, &table).unwrap(), Mode::Lenient).unwrap();
+
+        assert_eq!(summary.exactly_one, 1);
+        assert_eq!((summary.none(), summary.ambiguous()), (1, 1));
+        assert_eq!(
+            summary.problems[1].to_string(),
+            "Rucksack on line 3 shares 2 items: a, b"
+        );
+    }
+
+    #[test]
+    fn groups_are_reported_by_number_and_first_line() {
+        let lines = rucksacks(&["ab", "bc", "bd", "xy", "yz", "zx"]);
+        let groups = group_rucksacks(&lines, 3).unwrap();
+
+        let table = PriorityTable::standard();
+        let result = check_sharing(group_findings(&groups, &table), Mode::Strict);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Group 2 starting at line 4 shares no items"
+        );
+    }
+
+    #[test]
+    fn findings_name_items_from_the_table() {
+        let table = PriorityTable::with_digits();
+        let lines = rucksacks(&["a1b1", "1212", "x3y3"]);
+        let groups = group_rucksacks_with(&lines, 3, &table).unwrap();
+
+        let summary =
+            check_sharing(rucksack_findings(&lines, &table).unwrap(), Mode::Lenient).unwrap();
+        let groups = check_sharing(group_findings(&groups, &table), Mode::Lenient).unwrap();
+
+        assert_eq!(
+            summary.problems[0].to_string(),
+            "Rucksack on line 2 shares 2 items: 1, 2"
+        );
+        assert_eq!(
+            groups.problems[0].to_string(),
+            "Group 1 starting at line 1 shares no items"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Solution};
+
+pub struct SectionRange {
+    start: i32,
+    end: i32,
+}
+
+impl SectionRange {
+    fn contains(&self, other: &SectionRange) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &SectionRange) -> bool {
+        (self.start >= other.start && self.start <= other.end) ||
+        (self.end >= other.start && self.end <= other.end)
+    }
+
+    fn parse(line_number: usize, line: &str, text: &str) -> Result<SectionRange, ParseError> {
+        let (start_text, end_text) = text
+            .split_once('-')
+            .ok_or_else(|| ParseError::in_line(line_number, line, text, "a range like `2-4`"))?;
+
+        let parse_bound = |bound: &str| {
+            bound
+                .parse::<i32>()
+                .map_err(|_| ParseError::in_line(line_number, line, bound, "a section number"))
+        };
+
+        let start = parse_bound(start_text)?;
+        let end = parse_bound(end_text)?;
+
+        if start > end {
+            return Err(ParseError::in_line(line_number, line, text, "a range whose start is not after its end"));
+        }
+
+        Ok(SectionRange { start, end })
+    }
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<(SectionRange, SectionRange), ParseError> {
+    let (left, right) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::in_line(line_number, line, line, "two ranges like `2-4,6-8`"))?;
+
+    Ok((
+        SectionRange::parse(line_number, line, left)?,
+        SectionRange::parse(line_number, line, right)?,
+    ))
+}
+
+pub fn parse_input(input_data: &str) -> Result<Vec<(SectionRange, SectionRange)>, ParseError> {
+    input_data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(index + 1, line))
+        .collect()
+}
+
+pub fn get_contained_sections(sections: &[(SectionRange, SectionRange)]) -> Vec<&(SectionRange, SectionRange)> {
+    sections.iter().filter(|(left, right)| {
+        left.contains(right) || right.contains(left)
+    }).collect::<Vec<_>>()
+}
+
+pub fn get_overlapping_sections(sections: &[(SectionRange, SectionRange)]) -> Vec<&(SectionRange, SectionRange)> {
+    sections.iter().filter(|(left, right)| {
+        left.overlaps(right) || right.overlaps(left)
+    }).collect::<Vec<_>>()
+}
+
+/// Writes `pairs` lines of two random section ranges within 1-99.
+pub fn generate_input(rng: &mut Rng, pairs: usize) -> String {
+    let mut range = || {
+        let start = rng.between(1, 99);
+        format!("{}-{}", start, rng.between(start, 99))
+    };
+
+    (0..pairs.max(1))
+        .map(|_| format!("{},{}\n", range(), range()))
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "2",
+        part_two: "4",
+    };
+
+    type Input = Vec<(SectionRange, SectionRange)>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part_one(sections: &Self::Input) -> Answer {
+        get_contained_sections(sections).len().into()
+    }
+
+    fn part_two(sections: &Self::Input) -> Answer {
+        get_overlapping_sections(sections).len().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests{ 
+    use super::{generate_input, parse_input, SectionRange};
+    use aoc_core::rng::Rng;
+    use aoc_core::ParseError;
+
+    aoc_core::example_tests!(super::Day04);
+
+    #[test]
+    fn contains_checks_correctly_for_contained_sections() {
+        let left = SectionRange { start: 1, end: 10 };
+        let right = SectionRange { start: 2, end: 9 };
+        assert!(left.contains(&right));
+    }
+
+    #[test]
+    fn contains_checks_correctly_for_non_contained_sections() {
+        let left = SectionRange { start: 1, end: 10 };
+        let right = SectionRange { start: 2, end: 11 };
+        assert!(!left.contains(&right));
+    }
+
+    #[test]
+    fn overlaps_checks_correctly_for_overlapping_sections() {
+        let left = SectionRange { start: 1, end: 3 };
+        let right = SectionRange { start: 2, end: 4 };
+        assert!(left.overlaps(&right));
+        assert!(right.overlaps(&left));
+    }
+
+    #[test]
+    fn overlaps_checks_correctly_for_touching_sections() {
+        let left = SectionRange { start: 1, end: 3 };
+        let right = SectionRange { start: 3, end: 4 };
+        assert!(left.overlaps(&right));
+        assert!(right.overlaps(&left));
+    }
+
+    #[test]
+    fn overlaps_checks_correctly_for_non_overlapping_sections() {
+        let left = SectionRange { start: 1, end: 2 };
+        let right = SectionRange { start: 3, end: 4 };
+        assert!(!left.overlaps(&right));
+        assert!(!right.overlaps(&left));
+    }
+
+    #[test]
+    fn parse_input_reports_invalid_section_numbers() {
+        let result = parse_input("2-4,6-8\n35-7x,35-82\n");
+
+        assert_eq!(result.err(), Some(ParseError::new(2, 4, "7x", "a section number")));
+    }
+
+    #[test]
+    fn parse_input_reports_missing_ranges() {
+        let result = parse_input("2-4\n");
+
+        assert_eq!(result.err(), Some(ParseError::new(1, 1, "2-4", "two ranges like `2-4,6-8`")));
+    }
+
+    #[test]
+    fn generate_input_writes_valid_pairs() {
+        let input_data = generate_input(&mut Rng::new(4), 100);
+
+        assert_eq!(parse_input(&input_data).unwrap().len(), 100);
+    }
+}
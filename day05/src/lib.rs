@@ -0,0 +1,475 @@
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Solution};
+use regex::Regex;
+use std::fmt::Display;
+
+pub struct Move {
+    amount: i32,
+    from: i32,
+    to: i32,
+    line_number: usize,
+}
+
+impl Move {
+    pub fn new(amount: i32, from: i32, to: i32) -> Move {
+        Move {
+            amount,
+            from,
+            to,
+            line_number: 0,
+        }
+    }
+
+    /// Records the line of the input the move was read from, for errors.
+    pub fn at_line(self, line_number: usize) -> Move {
+        Move { line_number, ..self }
+    }
+}
+
+/// A move that takes more crates than its source stack holds at that point.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MoveError {
+    pub line_number: usize,
+    pub amount: usize,
+    pub from: usize,
+    pub available: usize,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line {}: cannot move {} crates from stack {}, which holds {}",
+            self.line_number, self.amount, self.from, self.available
+        )
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+pub struct Shipment {
+    pub stacks: Vec<Vec<String>>,
+    pub moves: Vec<Move>,
+}
+
+impl Shipment {
+    fn state(&self) -> Vec<Vec<&str>> {
+        self.stacks
+            .iter()
+            .map(|stack| stack.iter().map(|item| item.as_str()).collect())
+            .collect()
+    }
+}
+
+fn parse_crate(line_number: usize, row: &str, index: usize) -> Result<Option<String>, ParseError> {
+    let start = index * 4;
+    let cell = row.get(start..row.len().min(start + 3)).unwrap_or("");
+
+    if cell.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let mut chars = cell.chars();
+
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some('['), Some(item), Some(']')) if item.is_ascii_alphabetic() => Ok(Some(item.to_string())),
+        _ => Err(ParseError::in_line(line_number, row, cell, "a crate like `[A]`")),
+    }
+}
+
+pub fn parse_state(drawing: &[&str]) -> Result<Vec<Vec<String>>, ParseError> {
+    let (labels, rows) = drawing
+        .split_last()
+        .ok_or_else(|| ParseError::new(1, 1, "", "a crate drawing"))?;
+
+    let labels_line_number = drawing.len();
+    let mut stack_count = 0;
+
+    for label in labels.split_whitespace() {
+        stack_count += 1;
+
+        if label != stack_count.to_string() {
+            let expected = format!("stack number {}", stack_count);
+            return Err(ParseError::in_line(labels_line_number, labels, label, &expected));
+        }
+    }
+
+    let mut state = vec![Vec::new(); stack_count];
+
+    for (index, row) in rows.iter().enumerate().rev() {
+        let line_number = index + 1;
+
+        if let Some((position, c)) = row.char_indices().find(|(_, c)| !c.is_ascii()) {
+            let text = &row[position..position + c.len_utf8()];
+            return Err(ParseError::in_line(line_number, row, text, "a crate like `[A]`"));
+        }
+
+        let overflow = row.get(stack_count * 4..).unwrap_or("");
+
+        if !overflow.trim().is_empty() {
+            let text = overflow.trim();
+            return Err(ParseError::in_line(line_number, row, text, "no crates beyond the last stack"));
+        }
+
+        for (stack_index, stack) in state.iter_mut().enumerate() {
+            if let Some(item) = parse_crate(line_number, row, stack_index)? {
+                stack.push(item);
+            }
+        }
+    }
+
+    Ok(state)
+}
+
+pub fn parse_moves(input_lines: &[&str], first_line_number: usize, stack_count: usize) -> Result<Vec<Move>, ParseError> {
+    let move_pattern = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+
+    input_lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line_number = first_line_number + index;
+            let captures = move_pattern
+                .captures(line)
+                .ok_or_else(|| ParseError::in_line(line_number, line, line, "a move like `move 1 from 2 to 3`"))?;
+
+            let parse_number = |group: usize| {
+                let text = captures.get(group).unwrap().as_str();
+                text.parse::<i32>()
+                    .map_err(|_| ParseError::in_line(line_number, line, text, "a number that fits in 32 bits"))
+            };
+
+            let parse_stack = |group: usize| {
+                let stack = parse_number(group)?;
+
+                if stack < 1 || stack as usize > stack_count {
+                    let text = captures.get(group).unwrap().as_str();
+                    let expected = format!("a stack between 1 and {}", stack_count);
+                    return Err(ParseError::in_line(line_number, line, text, &expected));
+                }
+
+                Ok(stack)
+            };
+
+            Ok(Move::new(parse_number(1)?, parse_stack(2)?, parse_stack(3)?).at_line(line_number))
+        })
+        .collect()
+}
+
+pub fn parse_input(input_data: &str) -> Result<Shipment, ParseError> {
+    let mut input_lines = input_data.lines();
+
+    let drawing: Vec<&str> = input_lines
+        .by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .collect();
+    let moves: Vec<&str> = input_lines.collect();
+
+    let stacks = parse_state(&drawing)?;
+    let first_move_line_number = drawing.len() + 2;
+    let parsed_moves = parse_moves(&moves, first_move_line_number, stacks.len())?;
+
+    // Both crane models move the same number of crates, so one pass over the
+    // stack heights finds every move that would take too many.
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+    if let Err(err) = check_move_heights(&mut heights, &parsed_moves) {
+        let line = moves[err.line_number - first_move_line_number];
+        let amount = line.split(' ').nth(1).unwrap();
+        let expected = format!("at most {} crates, the height of stack {}", err.available, err.from);
+
+        return Err(ParseError::in_line(err.line_number, line, amount, &expected));
+    }
+
+    Ok(Shipment { stacks, moves: parsed_moves })
+}
+
+/// Applies the moves to the stack heights, stopping at the first move that
+/// takes more crates than its source stack holds.
+fn check_move_heights(heights: &mut [usize], moves: &[Move]) -> Result<(), MoveError> {
+    for mv in moves {
+        let from = (mv.from - 1) as usize;
+        let amount = mv.amount as usize;
+
+        if amount > heights[from] {
+            return Err(MoveError {
+                line_number: mv.line_number,
+                amount,
+                from: mv.from as usize,
+                available: heights[from],
+            });
+        }
+
+        heights[from] -= amount;
+        heights[(mv.to - 1) as usize] += amount;
+    }
+
+    Ok(())
+}
+
+pub fn process_crates_9000(state: &mut [Vec<&str>], moves: &[Move]) -> Result<(), MoveError> {
+    let mut heights: Vec<usize> = state.iter().map(Vec::len).collect();
+    check_move_heights(&mut heights, moves)?;
+
+    for mv in moves {
+        let mut moves_to_make = mv.amount;
+
+        while moves_to_make > 0 {
+            let from_stack = &mut state[(mv.from - 1) as usize];
+
+            let moved_item = from_stack.pop().unwrap();
+
+            let to_stack = &mut state[(mv.to - 1) as usize];
+            to_stack.push(moved_item);
+
+            moves_to_make -= 1;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn process_crates_9001(state: &mut [Vec<&str>], moves: &[Move]) -> Result<(), MoveError> {
+    let mut heights: Vec<usize> = state.iter().map(Vec::len).collect();
+    check_move_heights(&mut heights, moves)?;
+
+    for mv in moves {
+        let from_stack = &mut state[(mv.from - 1) as usize];
+        let moved_items = from_stack.split_off(from_stack.len() - mv.amount as usize);
+
+        let to_stack = &mut state[(mv.to - 1) as usize];
+        to_stack.append(&mut moved_items.clone());
+    }
+
+    Ok(())
+}
+
+/// Reads the top crate of every stack. Stacks that were emptied by the moves
+/// have no top crate and are left out of the code.
+pub fn get_code(state: &[Vec<&str>]) -> String {
+    state
+        .iter()
+        .filter_map(|stack| stack.last().copied())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// Writes a drawing of nine stacks followed by `moves` legal moves. No move
+/// ever empties a stack, so every stack still has a top crate at the end.
+pub fn generate_input(rng: &mut Rng, moves: usize) -> String {
+    let stack_count = 9;
+    let mut heights: Vec<usize> = (0..stack_count).map(|_| rng.between(2, 8)).collect();
+    let tallest = *heights.iter().max().unwrap();
+
+    let mut lines: Vec<String> = (0..tallest)
+        .rev()
+        .map(|level| {
+            let row: Vec<String> = heights
+                .iter()
+                .map(|height| {
+                    if *height > level {
+                        format!("[{}]", (b'A' + rng.below(26) as u8) as char)
+                    } else {
+                        "   ".to_string()
+                    }
+                })
+                .collect();
+
+            row.join(" ")
+        })
+        .collect();
+
+    let labels: Vec<String> = (1..=stack_count).map(|label| format!(" {} ", label)).collect();
+    lines.push(labels.join(" "));
+    lines.push(String::new());
+
+    for _ in 0..moves.max(1) {
+        // There are always more crates than stacks, so some stack can give
+        // away a crate without becoming empty.
+        let sources: Vec<usize> = (0..stack_count).filter(|&stack| heights[stack] > 1).collect();
+        let from = *rng.choose(&sources);
+        let to = (from + rng.between(1, stack_count - 1)) % stack_count;
+        let amount = rng.between(1, heights[from] - 1);
+
+        heights[from] -= amount;
+        heights[to] += amount;
+
+        lines.push(format!("move {} from {} to {}", amount, from + 1, to + 1));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "CMZ",
+        part_two: "MCD",
+    };
+
+    type Input = Shipment;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part_one(shipment: &Self::Input) -> Answer {
+        let mut shipping_state_9000 = shipment.state();
+
+        process_crates_9000(&mut shipping_state_9000, &shipment.moves)
+            .expect("moves are checked when parsing");
+
+        get_code(&shipping_state_9000).into()
+    }
+
+    fn part_two(shipment: &Self::Input) -> Answer {
+        let mut shipping_state_9001 = shipment.state();
+
+        process_crates_9001(&mut shipping_state_9001, &shipment.moves)
+            .expect("moves are checked when parsing");
+
+        get_code(&shipping_state_9001).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Move, MoveError};
+    use super::{generate_input, parse_input, parse_state, process_crates_9000, process_crates_9001};
+    use aoc_core::rng::Rng;
+    use aoc_core::{ParseError, Solution};
+
+    aoc_core::example_tests!(super::Day05);
+
+    fn is_state_equal(left: Vec<Vec<&str>>, right: Vec<Vec<&str>>) -> bool {
+        for (left_stack, right_stack) in left.iter().zip(right.iter()) {
+            if left_stack != right_stack {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    #[test]
+    fn process_crates_9000_works_correctly() {
+        let mut state = vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]];
+        let moves = vec![Move::new(2, 2, 1)];
+
+        let expected_state = vec![vec!["Z", "N", "D", "C"], vec!["M"], vec!["P"]];
+
+        process_crates_9000(&mut state, &moves).unwrap();
+
+        assert!(is_state_equal(expected_state, state));
+    }
+
+    #[test]
+    fn process_crates_9001_works_correctly() {
+        let mut state = vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]];
+        let moves = vec![Move::new(2, 2, 1)];
+
+        let expected_state = vec![vec!["Z", "N", "C", "D"], vec!["M"], vec!["P"]];
+
+        process_crates_9001(&mut state, &moves).unwrap();
+
+        assert!(is_state_equal(expected_state, state));
+    }
+
+    #[test]
+    fn parse_state_reads_stacks_bottom_up() {
+        let drawing = vec!["    [D]    ", "[N] [C]    ", "[Z] [M] [P]", " 1   2   3 "];
+
+        let state = parse_state(&drawing).unwrap();
+
+        assert_eq!(state, vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]]);
+    }
+
+    #[test]
+    fn parse_input_reports_invalid_moves() {
+        let input_data = "[Z] [M]\n 1   2 \n\nmove 1 from 2 to 1\nmove one from 1 to 2\n";
+
+        let result = parse_input(input_data);
+
+        assert_eq!(
+            result.err(),
+            Some(ParseError::new(5, 1, "move one from 1 to 2", "a move like `move 1 from 2 to 3`"))
+        );
+    }
+
+    #[test]
+    fn parse_input_reports_unknown_stacks() {
+        let input_data = "[Z] [M]\n 1   2 \n\nmove 1 from 3 to 1\n";
+
+        let result = parse_input(input_data);
+
+        assert_eq!(result.err(), Some(ParseError::new(4, 13, "3", "a stack between 1 and 2")));
+    }
+
+    #[test]
+    fn processors_reject_moves_from_short_stacks() {
+        let moves = vec![Move::new(1, 3, 1).at_line(5), Move::new(2, 3, 2).at_line(6)];
+        let error = MoveError {
+            line_number: 6,
+            amount: 2,
+            from: 3,
+            available: 0,
+        };
+
+        let mut state = vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]];
+        assert_eq!(process_crates_9000(&mut state, &moves), Err(error));
+
+        let mut state = vec![vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]];
+        assert_eq!(process_crates_9001(&mut state, &moves), Err(error));
+        assert_eq!(
+            error.to_string(),
+            "Line 6: cannot move 2 crates from stack 3, which holds 0"
+        );
+    }
+
+    #[test]
+    fn parse_input_reports_moves_from_short_stacks() {
+        let input_data = "[Z] [M]\n 1   2 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 2\n";
+
+        let result = parse_input(input_data);
+
+        assert_eq!(
+            result.err(),
+            Some(ParseError::new(5, 6, "3", "at most 2 crates, the height of stack 1"))
+        );
+    }
+
+    #[test]
+    fn parse_state_reports_invalid_crates() {
+        let drawing = vec!["[Z] (M)", " 1   2 "];
+
+        let result = parse_state(&drawing);
+
+        assert_eq!(result, Err(ParseError::new(1, 5, "(M)", "a crate like `[A]`")));
+    }
+
+    #[test]
+    fn emptied_stacks_are_left_out_of_the_code() {
+        let input_data = super::Day05::EXAMPLE.input.to_string() + "move 1 from 2 to 1\n";
+        let shipment = parse_input(&input_data).unwrap();
+
+        assert_eq!(super::Day05::part_one(&shipment).to_string(), "MZ");
+    }
+
+    #[test]
+    fn generate_input_writes_legal_moves() {
+        let input_data = generate_input(&mut Rng::new(5), 200);
+        let shipment = parse_input(&input_data).unwrap();
+
+        assert_eq!(shipment.stacks.len(), 9);
+        assert_eq!(shipment.moves.len(), 200);
+        assert_eq!(super::Day05::part_one(&shipment).to_string().len(), 9);
+        assert_eq!(super::Day05::part_two(&shipment).to_string().len(), 9);
+    }
+}
@@ -0,0 +1,253 @@
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Part, Solution};
+use std::ops::{Bound, RangeBounds};
+
+// Rust supports unicode string, so you'll need a special set of utilities to
+// correctly map between character positions and byte positions. That's what the
+// mumbo jumbo below is for.
+
+pub trait StringUtils {
+    fn substring(&self, start: usize, len: usize) -> &str;
+    fn slice(&self, range: impl RangeBounds<usize>) -> &str;
+}
+
+impl StringUtils for str {
+    fn substring(&self, start: usize, len: usize) -> &str {
+        let mut char_pos = 0;
+        let mut byte_start = 0;
+        let mut it = self.chars();
+        loop {
+            if char_pos == start {
+                break;
+            }
+            if let Some(c) = it.next() {
+                char_pos += 1;
+                byte_start += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+        char_pos = 0;
+        let mut byte_end = byte_start;
+        loop {
+            if char_pos == len {
+                break;
+            }
+            if let Some(c) = it.next() {
+                char_pos += 1;
+                byte_end += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+        &self[byte_start..byte_end]
+    }
+    fn slice(&self, range: impl RangeBounds<usize>) -> &str {
+        let start = match range.start_bound() {
+            Bound::Included(bound) | Bound::Excluded(bound) => *bound,
+            Bound::Unbounded => 0,
+        };
+        let len = match range.end_bound() {
+            Bound::Included(bound) => *bound + 1,
+            Bound::Excluded(bound) => *bound,
+            Bound::Unbounded => self.len(),
+        } - start;
+        self.substring(start, len)
+    }
+}
+
+// This is where the puzzle specific code starts.
+
+pub fn is_unique_sequence(sequence: &str) -> bool {
+    let characters: Vec<char> = sequence.chars().collect();
+    let mut i = 0;
+
+    while i < characters.len() {
+        let left = &characters[..i];
+
+        if left.iter().any(|o| *o == characters[i]) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+pub fn find_marker(input_data: &str, marker_size: usize) -> Option<usize> {
+    let mut i = marker_size;
+
+    // The marker may end at the very last character of the signal.
+    while i <= input_data.len() {
+        let sequence = input_data.slice(i - marker_size..i);
+
+        if is_unique_sequence(sequence) {
+            return Some(i);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+pub fn parse_signal(input_data: &str) -> Result<String, ParseError> {
+    let mut lines = input_data.lines();
+    let signal = lines.next().unwrap_or("");
+
+    if let Some((position, c)) = signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        let text = &signal[position..position + c.len_utf8()];
+        return Err(ParseError::in_line(1, signal, text, "a lowercase letter"));
+    }
+
+    if signal.is_empty() {
+        return Err(ParseError::new(1, 1, "", "a signal"));
+    }
+
+    if let Some((index, line)) = lines.enumerate().find(|(_, line)| !line.trim().is_empty()) {
+        return Err(ParseError::in_line(index + 2, line, line, "the end of the signal"));
+    }
+
+    Ok(signal.to_string())
+}
+
+/// Checks that `signal` has a marker of `marker_size` different letters.
+pub fn check_marker(signal: &str, marker_size: usize) -> Result<(), ParseError> {
+    match find_marker(signal, marker_size) {
+        Some(_) => Ok(()),
+        None => {
+            let expected = format!("a signal with {} different letters in a row", marker_size);
+            Err(ParseError::in_line(1, signal, signal, &expected))
+        }
+    }
+}
+
+/// Writes a signal of `length` characters with fourteen distinct letters
+/// planted in it. The noise before them only uses three letters, so both
+/// markers end up in or right next to the planted stretch.
+pub fn generate_input(rng: &mut Rng, length: usize) -> String {
+    let length = length.max(14);
+    let noise_length = rng.between(0, length - 14);
+
+    let mut marker: Vec<char> = ('d'..='z').collect();
+    rng.shuffle(&mut marker);
+
+    let noise: String = (0..noise_length).map(|_| *rng.choose(&['a', 'b', 'c'])).collect();
+    let rest: String = (noise_length + 14..length).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+
+    format!("{}{}{}\n", noise, marker[..14].iter().collect::<String>(), rest)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "7",
+        part_two: "19",
+    };
+
+    type Input = String;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_signal(input_data)
+    }
+
+    fn check(input_data: &Self::Input, part: Part) -> Result<(), ParseError> {
+        match part {
+            Part::One => check_marker(input_data, 4),
+            Part::Two => check_marker(input_data, 14),
+        }
+    }
+
+    fn part_one(input_data: &Self::Input) -> Answer {
+        find_marker(input_data, 4).expect("the marker is checked before part one").into()
+    }
+
+    fn part_two(input_data: &Self::Input) -> Answer {
+        find_marker(input_data, 14).expect("the marker is checked before part two").into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_marker, generate_input, is_unique_sequence, parse_signal, Day06};
+    use aoc_core::rng::Rng;
+    use aoc_core::{Answer, ParseError, Part, Puzzle};
+
+    aoc_core::example_tests!(super::Day06);
+
+    #[test]
+    fn unique_sequence_correctly_detected() {
+        assert!(is_unique_sequence("abcd"));
+        assert!(!is_unique_sequence("cdee"));
+    }
+
+    #[test]
+    fn find_marker_works_correctly() {
+        let input_data = vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+        ];
+
+        for (input_string, expected_marker) in input_data {
+            let actual_marker = find_marker(input_string, 4).expect("Unable to find marker");
+            assert_eq!(actual_marker, expected_marker);
+        }
+    }
+
+    #[test]
+    fn parse_signal_reports_invalid_characters() {
+        let result = parse_signal("bvwbjpl3bgvbh\n");
+
+        assert_eq!(result, Err(ParseError::new(1, 8, "3", "a lowercase letter")));
+    }
+
+    #[test]
+    fn generate_input_plants_a_marker() {
+        for seed in 0..20 {
+            let signal = parse_signal(&generate_input(&mut Rng::new(seed), 500)).unwrap();
+
+            assert_eq!(signal.len(), 500);
+            assert!(find_marker(&signal, 4).is_some());
+            assert!(find_marker(&signal, 14).is_some());
+        }
+    }
+
+    #[test]
+    fn find_marker_checks_the_last_window() {
+        assert_eq!(find_marker("aabcd", 4), Some(5));
+        assert_eq!(find_marker("abcd", 4), Some(4));
+        assert_eq!(find_marker("abc", 4), None);
+    }
+
+    #[test]
+    fn generate_input_plants_a_marker_in_short_signals() {
+        for length in 0..=20 {
+            for seed in 0..20 {
+                let signal = parse_signal(&generate_input(&mut Rng::new(seed), length)).unwrap();
+
+                assert_eq!(signal.len(), length.max(14));
+                assert!(find_marker(&signal, 4).is_some());
+                assert!(find_marker(&signal, 14).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn signals_without_a_marker_are_reported() {
+        let puzzle: &dyn Puzzle = &Day06;
+        let four = ParseError::new(1, 1, "aaaaaaa", "a signal with 4 different letters in a row");
+        let fourteen = ParseError::new(1, 1, "abcdabcd", "a signal with 14 different letters in a row");
+
+        assert_eq!(puzzle.solve("aaaaaaa\n", &[Part::One]).err(), Some(four));
+        assert_eq!(puzzle.solve("abcdabcd\n", &[Part::Two]).err(), Some(fourteen));
+        assert_eq!(puzzle.solve("abcdabcd\n", &[Part::One]).unwrap()[0].answer, Answer::Number(4));
+    }
+}
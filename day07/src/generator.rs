@@ -0,0 +1,107 @@
+use aoc_core::rng::Rng;
+
+struct Folder {
+    name: String,
+    files: Vec<(String, usize)>,
+    folders: Vec<usize>,
+}
+
+fn random_name(rng: &mut Rng, taken: &[&str]) -> String {
+    loop {
+        let length = rng.between(1, 8);
+        let mut name: String = (0..length)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+
+        if rng.below(2) == 0 {
+            name.push_str([".txt", ".dat", ".lst", ".log"][rng.below(4) as usize]);
+        }
+
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
+
+fn write_folder(folders: &[Folder], index: usize, lines: &mut Vec<String>) {
+    let folder = &folders[index];
+
+    lines.push("$ ls".to_string());
+
+    for child in &folder.folders {
+        lines.push(format!("dir {}", folders[*child].name));
+    }
+
+    for (name, size) in &folder.files {
+        lines.push(format!("{} {}", size, name));
+    }
+
+    for child in &folder.folders {
+        lines.push(format!("$ cd {}", folders[*child].name));
+        write_folder(folders, *child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+/// Writes a transcript that lists a random tree of `entries` files and
+/// folders exactly once. The total size stays well below the disk size, so
+/// part two always finds a folder to delete.
+pub fn generate_input(rng: &mut Rng, entries: usize) -> String {
+    let entries = entries.max(1);
+    let max_file_size = (35_000_000 / entries).clamp(1, 300_000);
+
+    let mut folders = vec![Folder {
+        name: "/".to_string(),
+        files: Vec::new(),
+        folders: Vec::new(),
+    }];
+
+    for entry in 0..entries {
+        let parent = rng.below(folders.len() as u64) as usize;
+        let taken: Vec<&str> = folders[parent]
+            .folders
+            .iter()
+            .map(|child| folders[*child].name.as_str())
+            .chain(folders[parent].files.iter().map(|(name, _)| name.as_str()))
+            .collect();
+        let name = random_name(rng, &taken);
+
+        // The first entry is always a folder, so part two has a candidate.
+        if entry == 0 || rng.below(4) == 0 {
+            folders.push(Folder {
+                name,
+                files: Vec::new(),
+                folders: Vec::new(),
+            });
+
+            let child = folders.len() - 1;
+            folders[parent].folders.push(child);
+        } else {
+            let size = rng.between(1, max_file_size);
+            folders[parent].files.push((name, size));
+        }
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    write_folder(&folders, 0, &mut lines);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_input;
+    use crate::{build_tree, parse_text, Day07};
+    use aoc_core::rng::Rng;
+    use aoc_core::Solution;
+
+    #[test]
+    fn generate_input_writes_a_consistent_transcript() {
+        let input_data = generate_input(&mut Rng::new(7), 300);
+        let tree = build_tree(&parse_text(&input_data).unwrap()).unwrap();
+
+        assert!(tree.borrow().total_size() < 40_000_000);
+        Day07::part_one(&tree);
+        Day07::part_two(&tree);
+    }
+}
@@ -1,7 +1,131 @@
 mod scanner;
 mod parser;
 mod tree_walker;
+mod generator;
 
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Solution};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use parser::Syntax;
+use scanner::Spanned;
+
+pub use generator::generate_input;
 pub use parser::parse_text;
 pub use tree_walker::build_tree;
-pub use tree_walker::TreeNode;
\ No newline at end of file
+pub use tree_walker::TreeNode;
+
+pub fn find_deletable_nodes(tree: &Rc<RefCell<TreeNode>>, expected_size: i64) -> Vec<Rc<RefCell<TreeNode>>> {
+    let mut deletable_nodes = Vec::new();
+
+    for child in &tree.borrow().children {
+        if child.borrow().total_size() < expected_size {
+            deletable_nodes.push(Rc::clone(child));
+        } 
+
+        deletable_nodes.append(&mut find_deletable_nodes(child, expected_size));
+    }
+
+    deletable_nodes
+}
+
+/// Checks that the session starts with `$ cd /`, so every listing in it has a
+/// place in the tree.
+fn check_starts_at_root(input_data: &str, commands: &[Spanned<Syntax>]) -> Result<(), ParseError> {
+    let expected = "a session starting with `$ cd /`";
+
+    match commands.first() {
+        None => Err(ParseError::new(1, 1, "", expected)),
+        Some(command) if command.value == (Syntax::ChangeDirectory { target: "/".to_string() }) => Ok(()),
+        Some(command) => {
+            let line = input_data.lines().nth(command.line - 1).unwrap_or("");
+            Err(ParseError::in_line(command.line, line, line.trim(), expected))
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "95437",
+        part_two: "24933642",
+    };
+
+    type Input = Rc<RefCell<TreeNode>>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        let commands = parse_text(input_data)?;
+        check_starts_at_root(input_data, &commands)?;
+        build_tree(&commands)
+    }
+
+    fn part_one(tree: &Self::Input) -> Answer {
+        find_deletable_nodes(tree, 100_000)
+            .iter().map(|node| Rc::clone(node).borrow().total_size())
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part_two(tree: &Self::Input) -> Answer {
+        let total_size = tree.borrow().total_size();
+        let drive_size = 70_000_000;
+        let update_size = 30_000_000;
+        let unused_size = drive_size - total_size;
+        let required_size = update_size - unused_size;
+
+        let all_folders = tree.borrow().flatten();
+
+        let mut candidate_folders: Vec<&Rc<RefCell<TreeNode>>> = all_folders.iter()
+            .filter(|folder| folder.borrow().total_size() > required_size)
+            .collect();
+
+        candidate_folders.sort_by(|a, b| {
+            let a_size = a.borrow().total_size();
+            let b_size = b.borrow().total_size();
+
+            a_size.cmp(&b_size)
+        });
+
+        // Deleting everything always frees enough space, so the root is the
+        // answer when no folder below it is big enough.
+        candidate_folders
+            .first()
+            .map(|folder| folder.borrow().total_size())
+            .unwrap_or(total_size)
+            .into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day07;
+    use aoc_core::{Answer, ParseError, Solution};
+
+    aoc_core::example_tests!(super::Day07);
+
+    #[test]
+    fn sessions_must_start_at_the_root() {
+        let expected = "a session starting with `$ cd /`";
+
+        assert_eq!(Day07::parse("").err(), Some(ParseError::new(1, 1, "", expected)));
+        assert_eq!(
+            Day07::parse("$ ls\n100 a.txt\n").err(),
+            Some(ParseError::new(1, 1, "$ ls", expected))
+        );
+    }
+
+    #[test]
+    fn the_root_is_deleted_when_no_folder_is_big_enough() {
+        let tree = Day07::parse("$ cd /\n$ ls\n100 a.txt\n").unwrap();
+
+        assert_eq!(Day07::part_two(&tree), Answer::Number(100));
+    }
+}
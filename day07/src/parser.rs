@@ -1,5 +1,6 @@
 use std::fmt::Display;
-use crate::scanner::{get_tokens, Token};
+use aoc_core::ParseError;
+use crate::scanner::{get_tokens, Spanned, Token};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Syntax {
@@ -28,136 +29,140 @@ impl Display for Syntax {
     }
 }
 
-fn parse_cd(it: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> Syntax {
-    let target_token = it.next().expect("Expected another token. Got end of stream.");
+type TokenStream<'a> = std::iter::Peekable<std::slice::Iter<'a, Spanned<Token>>>;
 
-    let target = match target_token {
-        Token::String { value } => value.clone(),
-        _ => panic!("Invalid token {}", target_token),
-    };
+fn unexpected(token: &Spanned<Token>, expected: &str) -> ParseError {
+    ParseError::new(token.line, token.column, &token.value.source_text(), expected)
+}
 
-    let postfix_token = it.next().expect("Expected another token. Got end of stream.");
+fn next_token<'a>(it: &mut TokenStream<'a>) -> Result<&'a Spanned<Token>, ParseError> {
+    it.next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "another token"))
+}
 
-    match postfix_token {
-        Token::NewLine | Token::EndOfStream => {},
-        _ => panic!("Invalid token {}", postfix_token),
-    };
+fn parse_end_of_line(it: &mut TokenStream) -> Result<(), ParseError> {
+    let postfix_token = next_token(it)?;
 
-    Syntax::ChangeDirectory { target }
+    match postfix_token.value {
+        Token::NewLine | Token::EndOfStream => Ok(()),
+        _ => Err(unexpected(postfix_token, "the end of the line")),
+    }
 }
 
-fn parse_ls(it: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> Syntax {
-    let postfix_token = it.next().expect("Expected another token. Got end of stream.");
+fn parse_cd(it: &mut TokenStream) -> Result<Syntax, ParseError> {
+    let target_token = next_token(it)?;
 
-    match postfix_token {
-        Token::NewLine | Token::EndOfStream => {},
-        _ => panic!("Invalid token {}", postfix_token),
+    let target = match &target_token.value {
+        Token::String { value } => value.clone(),
+        _ => return Err(unexpected(target_token, "a directory name")),
     };
 
-    Syntax::ListContents
+    parse_end_of_line(it)?;
+
+    Ok(Syntax::ChangeDirectory { target })
+}
+
+fn parse_ls(it: &mut TokenStream) -> Result<Syntax, ParseError> {
+    parse_end_of_line(it)?;
+
+    Ok(Syntax::ListContents)
 }
 
-fn parse_command(it: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> Syntax {
-    let _prefix = it.next().expect("Expected another token. Got end of stream.");
+fn parse_command(it: &mut TokenStream) -> Result<Syntax, ParseError> {
+    let _prefix = next_token(it)?;
 
-    let name_token = it.next().expect("Expected another token. Got end of stream.");
+    let name_token = next_token(it)?;
 
-    let name = match name_token {
+    let name = match &name_token.value {
         Token::String { value } => value.clone(),
-        _ => unreachable!(),
+        _ => return Err(unexpected(name_token, "a command `cd` or `ls`")),
     };
 
     match name.as_str() {
         "cd" => parse_cd(it),
         "ls" => parse_ls(it),
-        _ => panic!("Invalid token {}", name_token),
+        _ => Err(unexpected(name_token, "a command `cd` or `ls`")),
     }
 }
 
-fn parse_directory(it: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> Syntax {
-    let _prefix = it.next();
+fn parse_directory(it: &mut TokenStream) -> Result<Syntax, ParseError> {
+    let prefix_token = next_token(it)?;
 
-    let name = match it.next() {
-        Some(Token::String { value }) => value.clone(),
-        _ => panic!("Invalid token"),
-    };
+    if prefix_token.value != (Token::String { value: "dir".to_string() }) {
+        return Err(unexpected(prefix_token, "`dir`, a file size or a command"));
+    }
 
-    let postfix = it.next();
+    let name_token = next_token(it)?;
 
-    match postfix {
-        Some(Token::NewLine) | Some(Token::EndOfStream) => {},
-        _ => panic!("Invalid token {}", postfix.unwrap()),
+    let name = match &name_token.value {
+        Token::String { value } => value.clone(),
+        _ => return Err(unexpected(name_token, "a directory name")),
     };
 
-    Syntax::Directory { name }
-}
+    parse_end_of_line(it)?;
 
-fn parse_file(it: &mut std::iter::Peekable<std::slice::Iter<Token>>) -> Syntax {
-    let size_token =  it.next().expect("Expected another token. Got end of stream.");
+    Ok(Syntax::Directory { name })
+}
 
-    let size = if let Token::Number { value } = size_token {
-        value.clone()
-    } else {
-        panic!("Invalid token {}", size_token);
-    };
-    
-    let name_token = it.next().expect("Expected another token. Got end of stream.");
+fn parse_file(it: &mut TokenStream) -> Result<Syntax, ParseError> {
+    let size_token = next_token(it)?;
 
-    let name = if let Token::String { value } = name_token {
-        value.clone()
-    } else {
-        panic!("Invalid token {}", name_token);
+    let size = match &size_token.value {
+        Token::Number { value } => *value,
+        _ => return Err(unexpected(size_token, "a file size")),
     };
 
-    let postfix_token = it.next().expect("Expected another token. Got end of stream.");
+    let name_token = next_token(it)?;
 
-    match postfix_token {
-        Token::NewLine | Token::EndOfStream => {},
-        _ => panic!("Invalid token {}", postfix_token),
+    let name = match &name_token.value {
+        Token::String { value } => value.clone(),
+        _ => return Err(unexpected(name_token, "a file name")),
     };
 
-    Syntax::File {
+    parse_end_of_line(it)?;
+
+    Ok(Syntax::File {
         name,
         size,
-    }
+    })
 }
 
-pub fn parse_text(input: &str) -> Vec<Syntax> {
-    let tokens = get_tokens(input);
+pub fn parse_text(input: &str) -> Result<Vec<Spanned<Syntax>>, ParseError> {
+    let tokens = get_tokens(input)?;
     let mut it = tokens.iter().peekable();
-    let mut syntax: Vec<Syntax> = Vec::new();
+    let mut syntax: Vec<Spanned<Syntax>> = Vec::new();
 
     while let Some(token) = it.peek() {
-        match token {
-            Token::Prefix => {
-                let command = parse_command(&mut it);
-                syntax.push(command);
-            }
-            Token::String { value: _ } => {
-                let directory = parse_directory(&mut it);
-                syntax.push(directory);
-            }
-            Token::Number { value: _ } => {
-                let file = parse_file(&mut it);
-                syntax.push(file);
-            }
+        let (line, column) = (token.line, token.column);
+
+        let value = match token.value {
+            Token::Prefix => parse_command(&mut it)?,
+            Token::String { value: _ } => parse_directory(&mut it)?,
+            Token::Number { value: _ } => parse_file(&mut it)?,
             _ => {
                 it.next();
+                continue;
             }
-        }
+        };
+
+        syntax.push(Spanned { value, line, column });
     }
 
-    syntax
+    Ok(syntax)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn syntax_values(input: &str) -> Vec<Syntax> {
+        parse_text(input).unwrap().into_iter().map(|syntax| syntax.value).collect()
+    }
+
     #[test]
     fn test_parse_cd_command() {
         let input = "$ cd /";
-        let syntax = parse_text(input);
+        let syntax = syntax_values(input);
 
         let expected_syntax = vec![super::Syntax::ChangeDirectory {
             target: "/".to_string(),
@@ -169,7 +174,7 @@ mod tests {
     #[test]
     fn test_parse_cd_command_parent() {
         let input = "$ cd ..";
-        let syntax = parse_text(input);
+        let syntax = syntax_values(input);
 
         let expected_syntax = vec![super::Syntax::ChangeDirectory {
             target: "..".to_string(),
@@ -181,7 +186,7 @@ mod tests {
     #[test]
     fn test_parse_ls_command() {
         let input = "$ ls";
-        let syntax = parse_text(input);
+        let syntax = syntax_values(input);
 
         let expected_syntax = vec![Syntax::ListContents];
 
@@ -191,7 +196,7 @@ mod tests {
     #[test]
     fn test_parse_directory() {
         let input = "dir test";
-        let syntax = parse_text(input);
+        let syntax = syntax_values(input);
 
         let expected_syntax = vec![Syntax::Directory {
             name: "test".to_string(),
@@ -203,7 +208,7 @@ mod tests {
     #[test]
     fn test_parse_file() {
         let input = "1024 test.txt";
-        let syntax = parse_text(input);
+        let syntax = syntax_values(input);
 
         let expected_syntax = vec![Syntax::File {
             name: "test.txt".to_string(),
@@ -212,4 +217,18 @@ mod tests {
 
         assert_eq!(syntax, expected_syntax);
     }
+
+    #[test]
+    fn test_parse_unknown_command() {
+        let error = parse_text("$ cd /\n$ rm x").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 3, "rm", "a command `cd` or `ls`"));
+    }
+
+    #[test]
+    fn test_parse_file_without_name() {
+        let error = parse_text("$ ls\n1024\n").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 5, "", "a file name"));
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_core::ParseError;
 use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -21,23 +22,83 @@ impl Display for Token {
     }
 }
 
-fn get_number(it: &mut std::iter::Peekable<std::str::Chars>) -> i32 {
-    let mut value = 0;
-    while let Some(&c) = it.peek() {
+impl Token {
+    pub fn source_text(&self) -> String {
+        match self {
+            Token::Prefix => "$".to_string(),
+            Token::String { value } => value.clone(),
+            Token::Number { value } => value.to_string(),
+            Token::NewLine | Token::EndOfStream => String::new(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub line: usize,
+    pub column: usize,
+}
+
+struct Cursor<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Cursor<'a> {
+        Cursor {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn span<T>(&self, value: T, line: usize, column: usize) -> Spanned<T> {
+        Spanned { value, line, column }
+    }
+}
+
+fn get_number(it: &mut Cursor) -> Result<i32, ParseError> {
+    let (line, column) = (it.line, it.column);
+    let mut digits = String::new();
+
+    while let Some(c) = it.peek() {
         match c {
             '0'..='9' => {
-                value = value * 10 + (c as i32 - '0' as i32);
+                digits.push(c);
                 it.next();
             }
             _ => break,
         }
     }
-    value
+
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(line, column, &digits, "a file size that fits in 32 bits"))
 }
 
-fn get_string(it: &mut std::iter::Peekable<std::str::Chars>) -> String {
+fn get_string(it: &mut Cursor) -> String {
     let mut value = String::new();
-    while let Some(&c) = it.peek() {
+    while let Some(c) = it.peek() {
         match c {
             'a'..='z' | '.' | '-' | '_' | '/' => {
                 value.push(c);
@@ -50,50 +111,57 @@ fn get_string(it: &mut std::iter::Peekable<std::str::Chars>) -> String {
     value
 }
 
-pub fn get_tokens(input: &str) -> Vec<Token> {
-    let mut it = input.chars().peekable();
+pub fn get_tokens(input: &str) -> Result<Vec<Spanned<Token>>, ParseError> {
+    let mut it = Cursor::new(input);
     let mut tokens = Vec::new();
 
-    while let Some(&c) = it.peek() {
+    while let Some(c) = it.peek() {
+        let (line, column) = (it.line, it.column);
+
         match c {
             '0'..='9' => {
-                let value = get_number(&mut it);
-                tokens.push(Token::Number { value });
+                let value = get_number(&mut it)?;
+                tokens.push(it.span(Token::Number { value }, line, column));
             }
             '$' => {
                 it.next();
-                tokens.push(Token::Prefix);
+                tokens.push(it.span(Token::Prefix, line, column));
             }
             'a'..='z' |'/' | '.'| '_' | '-' => {
                 let value = get_string(&mut it);
-                tokens.push(Token::String { value });
+                tokens.push(it.span(Token::String { value }, line, column));
             }
             '\n' | '\r' => {
                 it.next();
 
-                if let Some(nc) = it.peek() {
-                    if *nc == '\n' {
-                        it.next();
-                    }
+                if c == '\r' && it.peek() == Some('\n') {
+                    it.next();
                 }
 
-                tokens.push(Token::NewLine);
+                tokens.push(it.span(Token::NewLine, line, column));
             }
-            _ => {
+            ' ' | '\t' => {
                 it.next();
             }
+            _ => {
+                return Err(ParseError::new(line, column, &c.to_string(), "a command, directory or file"));
+            }
         }
     }
 
-    tokens.push(Token::EndOfStream);
+    tokens.push(it.span(Token::EndOfStream, it.line, it.column));
 
-    tokens
+    Ok(tokens)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn token_values(input: &str) -> Vec<Token> {
+        get_tokens(input).unwrap().into_iter().map(|token| token.value).collect()
+    }
+
     #[test]
     fn test_get_tokens_single_line() {
         let input = "$ cd /";
@@ -108,7 +176,7 @@ mod tests {
             Token::EndOfStream
         ];
 
-        let tokens = get_tokens(input);
+        let tokens = token_values(input);
 
         assert_eq!(tokens, expected_tokens);
     }
@@ -127,7 +195,7 @@ mod tests {
             Token::EndOfStream
         ];
 
-        let tokens = get_tokens(input);
+        let tokens = token_values(input);
 
         assert_eq!(tokens, expected_tokens);
     }
@@ -157,7 +225,7 @@ mod tests {
             Token::EndOfStream
         ];
 
-        let tokens = get_tokens(input);
+        let tokens = token_values(input);
 
         assert_eq!(tokens, expected_tokens);
     }
@@ -165,7 +233,7 @@ mod tests {
     #[test]
     fn test_get_tokens_dots() {
         let input = "test.txt";
-        let tokens = get_tokens(input);
+        let tokens = token_values(input);
 
         let expected_tokens = vec![
             Token::String {
@@ -176,4 +244,20 @@ mod tests {
         
         assert_eq!(tokens, expected_tokens);
     }
+
+    #[test]
+    fn test_get_tokens_positions() {
+        let tokens = get_tokens("$ ls\n14848514 b.txt").unwrap();
+
+        let positions: Vec<(usize, usize)> = tokens.iter().map(|token| (token.line, token.column)).collect();
+
+        assert_eq!(positions, vec![(1, 1), (1, 3), (1, 5), (2, 1), (2, 10), (2, 15)]);
+    }
+
+    #[test]
+    fn test_get_tokens_unexpected_character() {
+        let error = get_tokens("$ cd /\ndir Test").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 5, "T", "a command, directory or file"));
+    }
 }
@@ -1,4 +1,6 @@
 use crate::parser::Syntax;
+use crate::scanner::Spanned;
+use aoc_core::ParseError;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -20,7 +22,7 @@ impl TreeNode {
         }
     }
 
-    fn find_child(&self, name: &String) -> Option<Rc<RefCell<TreeNode>>> {
+    fn find_child(&self, name: &str) -> Option<Rc<RefCell<TreeNode>>> {
         for child in &self.children {
             if child.borrow().name == *name {
                 return Some(Rc::clone(child));
@@ -64,22 +66,32 @@ impl TreeNode {
     }
 }
 
-pub fn build_tree(commands: &Vec<Syntax>) -> Rc<RefCell<TreeNode>> {
+fn change_directory_error(command: &Spanned<Syntax>, expected: &str) -> ParseError {
+    let text = format!("$ {}", command.value);
+    ParseError::new(command.line, command.column, &text, expected)
+}
+
+pub fn build_tree(commands: &[Spanned<Syntax>]) -> Result<Rc<RefCell<TreeNode>>, ParseError> {
     let root = Rc::new(RefCell::new(TreeNode::new("/".to_string(), None)));
     let mut current = Rc::clone(&root);
 
     for command in commands {
-        match command {
+        match &command.value {
             Syntax::ChangeDirectory { target } => {
                 match target.as_str() {
                     ".." => {
                         let current_clone = Rc::clone(&current);
-                        current = Rc::clone(current_clone.borrow().parent.as_ref().unwrap());
+                        let parent = current_clone.borrow().parent.clone()
+                            .ok_or_else(|| change_directory_error(command, "a directory below the root"))?;
+
+                        current = parent;
                     }
                     "/" => current = Rc::clone(&root),
                     _ => {
                         let current_clone = Rc::clone(&current);
-                        let child = current_clone.borrow().find_child(target).unwrap();
+                        let child = current_clone.borrow().find_child(target)
+                            .filter(|child| child.borrow().size.is_none())
+                            .ok_or_else(|| change_directory_error(command, "a directory listed by `ls`"))?;
 
                         current = Rc::clone(&child);
                     }
@@ -99,5 +111,27 @@ pub fn build_tree(commands: &Vec<Syntax>) -> Rc<RefCell<TreeNode>> {
         }
     }
 
-    root
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_text;
+
+    #[test]
+    fn test_build_tree_sums_sizes() {
+        let commands = parse_text("$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt").unwrap();
+        let tree = build_tree(&commands).unwrap();
+
+        assert_eq!(tree.borrow().total_size(), 150);
+    }
+
+    #[test]
+    fn test_build_tree_unknown_directory() {
+        let commands = parse_text("$ cd /\n$ ls\ndir a\n$ cd b").unwrap();
+        let error = build_tree(&commands).unwrap_err();
+
+        assert_eq!(error, ParseError::new(4, 1, "$ cd b", "a directory listed by `ls`"));
+    }
 }
@@ -0,0 +1,169 @@
+use aoc_core::rng::Rng;
+use aoc_core::{Answer, Example, ParseError, Solution};
+
+fn parse_row(line_number: usize, line: &str) -> Result<Vec<i32>, ParseError> {
+    line.char_indices()
+        .map(|(position, c)| match c.to_digit(10) {
+            Some(tree_height) => Ok(tree_height as i32),
+            None => {
+                let text = &line[position..position + c.len_utf8()];
+                Err(ParseError::in_line(line_number, line, text, "a tree height 0-9"))
+            }
+        })
+        .collect()
+}
+
+pub fn parse_input(input_data: &str) -> Result<(Vec<Vec<i32>>, usize, usize), ParseError> {
+    let grid: Vec<Vec<i32>> = input_data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_row(index + 1, line))
+        .collect::<Result<_, _>>()?;
+
+    let width = match grid.first() {
+        Some(row) if !row.is_empty() => row.len(),
+        _ => return Err(ParseError::new(1, 1, "", "a row of trees")),
+    };
+
+    if let Some(index) = grid.iter().position(|row| row.len() != width) {
+        let line = input_data.lines().nth(index).unwrap();
+        let expected = format!("a row of {} trees", width);
+        return Err(ParseError::in_line(index + 1, line, line, &expected));
+    }
+
+    let height = grid.len();
+
+    Ok((grid, width, height))
+}
+
+fn lines_of_sight(x: usize, y: usize, width: usize, height: usize) -> Vec<Vec<(usize, usize)>> {
+    vec![
+        (0..y).rev().map(|ty| (x, ty)).collect(),
+        (y + 1..height).map(|ty| (x, ty)).collect(),
+        (0..x).rev().map(|tx| (tx, y)).collect(),
+        (x + 1..width).map(|tx| (tx, y)).collect(),
+    ]
+}
+
+pub fn is_visible(grid: &[Vec<i32>], x: usize, y: usize, width: usize, height: usize) -> bool {
+    let tree_height = grid[y][x];
+
+    lines_of_sight(x, y, width, height)
+        .iter()
+        .any(|line| line.iter().all(|&(tx, ty)| grid[ty][tx] < tree_height))
+}
+
+pub fn scenic_score(grid: &[Vec<i32>], x: usize, y: usize, width: usize, height: usize) -> usize {
+    let tree_height = grid[y][x];
+
+    lines_of_sight(x, y, width, height)
+        .iter()
+        .map(|line| {
+            let blocking_tree = line.iter().position(|&(tx, ty)| grid[ty][tx] >= tree_height);
+
+            match blocking_tree {
+                Some(index) => index + 1,
+                None => line.len(),
+            }
+        })
+        .product()
+}
+
+/// Writes a square grid of random tree heights, `size` trees wide.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size).map(|_| (b'0' + rng.below(10) as u8) as char).collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "21",
+        part_two: "8",
+    };
+
+    type Input = (Vec<Vec<i32>>, usize, usize);
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part_one((grid, width, height): &Self::Input) -> Answer {
+        (0..*height)
+            .flat_map(|y| (0..*width).map(move |x| (x, y)))
+            .filter(|&(x, y)| is_visible(grid, x, y, *width, *height))
+            .count()
+            .into()
+    }
+
+    fn part_two((grid, width, height): &Self::Input) -> Answer {
+        (0..*height)
+            .flat_map(|y| (0..*width).map(move |x| (x, y)))
+            .map(|(x, y)| scenic_score(grid, x, y, *width, *height))
+            .max()
+            .unwrap()
+            .into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_input, is_visible, parse_input, scenic_score};
+    use aoc_core::rng::Rng;
+    use aoc_core::ParseError;
+
+    aoc_core::example_tests!(super::Day08);
+
+    const EXAMPLE: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    #[test]
+    fn is_visible_detects_edge_and_hidden_trees() {
+        let (grid, width, height) = parse_input(EXAMPLE).unwrap();
+
+        assert!(is_visible(&grid, 0, 0, width, height));
+        assert!(is_visible(&grid, 1, 1, width, height));
+        assert!(!is_visible(&grid, 3, 1, width, height));
+    }
+
+    #[test]
+    fn scenic_score_multiplies_viewing_distances() {
+        let (grid, width, height) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(scenic_score(&grid, 2, 1, width, height), 4);
+        assert_eq!(scenic_score(&grid, 2, 3, width, height), 8);
+    }
+
+    #[test]
+    fn parse_input_reports_invalid_heights() {
+        let result = parse_input("30373\n25a12\n");
+
+        assert_eq!(result, Err(ParseError::new(2, 3, "a", "a tree height 0-9")));
+    }
+
+    #[test]
+    fn parse_input_reports_ragged_rows() {
+        let result = parse_input("30373\n2551\n");
+
+        assert_eq!(result, Err(ParseError::new(2, 1, "2551", "a row of 5 trees")));
+    }
+
+    #[test]
+    fn generate_input_writes_a_square_grid() {
+        let (_, width, height) = parse_input(&generate_input(&mut Rng::new(8), 40)).unwrap();
+
+        assert_eq!((width, height), (40, 40));
+    }
+}
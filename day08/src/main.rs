@@ -1,19 +1,9 @@
-use std::fs;
-
-fn parse_input() -> (Vec<Vec<i32>>, usize, usize) {
-    let input_data = fs::read_to_string("data/input.txt").expect("Can't find the input file");
-
-    let grid: Vec<Vec<i32>> = input_data
-        .lines()
-        .map(|line| line.chars().map(|c| c as i32 - 0x30).collect())
-        .collect();
-
-    let width = grid.first().unwrap().len();
-    let height = grid.len();
-
-    (grid, width, height)
-}
+use aoc_core::{input, Solution};
+use day08::Day08;
 
 fn main() {
-    let (grid, width, height) = parse_input();
+    let forest = input::parse_or_exit::<Day08>();
+
+    println!("Visible trees: {}", Day08::part_one(&forest));
+    println!("Highest scenic score: {}", Day08::part_two(&forest));
 }
@@ -0,0 +1,72 @@
+use crate::cli::{BenchArgs, DaySelection, OutputFormat};
+use crate::{days, read_input};
+use aoc_core::bench::{format_duration, BenchReport};
+use aoc_core::{json, Puzzle};
+
+fn bench_puzzle(puzzle: &dyn Puzzle, args: &BenchArgs) -> Result<BenchReport, String> {
+    let (source_name, input_data) = read_input(puzzle, &args.input)?;
+
+    puzzle
+        .bench(&input_data, &args.options)
+        .map_err(|err| err.with_file(&source_name).render(&input_data))
+}
+
+fn print_text(report: &BenchReport) {
+    println!(
+        "Day {:02} ({} iterations, {} warm-up)",
+        report.day, report.options.iterations, report.options.warmup
+    );
+    println!(
+        "  {:<10} {:>12} {:>12} {:>12} {:>12}",
+        "phase", "min", "median", "mean", "std dev"
+    );
+
+    for (phase, stats) in report.phases() {
+        println!(
+            "  {:<10} {:>12} {:>12} {:>12} {:>12}",
+            phase,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.std_dev)
+        );
+    }
+}
+
+pub fn bench(selection: DaySelection, args: &BenchArgs) -> Result<(), String> {
+    let puzzles = match selection {
+        DaySelection::Single(day) => {
+            vec![days::find(day).ok_or_else(|| format!("Day {} has not been solved yet", day))?]
+        }
+        DaySelection::All => days::all(),
+    };
+
+    let mut reports = Vec::new();
+
+    for puzzle in puzzles {
+        let report = bench_puzzle(puzzle, args)?;
+
+        if args.format == OutputFormat::Text {
+            print_text(&report);
+        }
+
+        reports.push(report);
+    }
+
+    match args.format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => println!(
+            "{}",
+            json::array(reports.iter().map(|report| report.to_json().to_string()))
+        ),
+        OutputFormat::Csv => {
+            println!("{}", BenchReport::CSV_HEADER);
+            reports
+                .iter()
+                .flat_map(BenchReport::to_csv)
+                .for_each(|row| println!("{}", row));
+        }
+    }
+
+    Ok(())
+}
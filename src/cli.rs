@@ -0,0 +1,465 @@
+use aoc_core::bench::BenchOptions;
+use aoc_core::Part;
+
+pub const USAGE: &str = "Usage:
+    adventofcode run <day> [--part 1|2] [--input <path>|-] [--example] [--format text|json|csv]
+    adventofcode run 1 [--top <k>] [--input <path>|-] [--example]
+    adventofcode run --all [--part 1|2] [--example] [--format text|json|csv]
+    adventofcode verify [<day>]
+    adventofcode new <day>
+    adventofcode generate <day> [--size <n>] [--seed <n>]
+    adventofcode bench <day>|--all [--iterations <n>] [--warmup <n>] [--input <path>|-] [--example] [--format text|json|csv]";
+
+#[derive(Debug, PartialEq)]
+pub enum DaySelection {
+    Single(u32),
+    All,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub enum InputSelection {
+    #[default]
+    Default,
+    Given(String),
+    Example,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct RunOptions {
+    pub part: Option<Part>,
+    pub input: InputSelection,
+    pub format: OutputFormat,
+    /// Lists the `k` heaviest elves after the answers of day 1.
+    pub top: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct BenchArgs {
+    pub input: InputSelection,
+    pub options: BenchOptions,
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: DaySelection,
+        options: RunOptions,
+    },
+    Verify {
+        days: DaySelection,
+    },
+    Bench {
+        days: DaySelection,
+        args: BenchArgs,
+    },
+    New {
+        day: u32,
+    },
+    Generate {
+        day: u32,
+        size: usize,
+        seed: u64,
+    },
+}
+
+fn parse_day(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid day '{}', expected a number", value))
+}
+
+fn parse_part(value: Option<&String>) -> Result<Part, String> {
+    match value.map(|v| v.as_str()) {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(other) => Err(format!("Invalid part '{}', expected 1 or 2", other)),
+        None => Err("Missing value for --part".to_string()),
+    }
+}
+
+fn parse_count(flag: &str, value: Option<&String>) -> Result<usize, String> {
+    let value = value.ok_or(format!("Missing value for {}", flag))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{}' for {}, expected a number", value, flag))
+}
+
+fn parse_seed(value: Option<&String>) -> Result<u64, String> {
+    let value = value.ok_or("Missing value for --seed")?;
+
+    value
+        .parse()
+        .map_err(|_| format!("Invalid seed '{}', expected a number from 0 to {}", value, u64::MAX))
+}
+
+fn parse_format(value: Option<&String>) -> Result<OutputFormat, String> {
+    match value.map(|v| v.as_str()) {
+        Some("text") => Ok(OutputFormat::Text),
+        Some("json") => Ok(OutputFormat::Json),
+        Some("csv") => Ok(OutputFormat::Csv),
+        Some(other) => Err(format!("Invalid format '{}', expected text, json or csv", other)),
+        None => Err("Missing value for --format".to_string()),
+    }
+}
+
+fn select_input(selected: &mut InputSelection, input: InputSelection) -> Result<(), String> {
+    if *selected != InputSelection::Default {
+        return Err("Use either --input or --example, not both".to_string());
+    }
+
+    *selected = input;
+    Ok(())
+}
+
+fn check_input(days: &DaySelection, input: &InputSelection) -> Result<(), String> {
+    if *days == DaySelection::All && matches!(input, InputSelection::Given(_)) {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    Ok(())
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let mut it = args.iter();
+    let mut days = None;
+    let mut options = RunOptions::default();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--all" => days = Some(DaySelection::All),
+            "--part" => options.part = Some(parse_part(it.next())?),
+            "--format" => options.format = parse_format(it.next())?,
+            "--top" => options.top = Some(parse_count(arg, it.next())?),
+            "--input" => {
+                let path = it.next().ok_or("Missing value for --input")?;
+                select_input(&mut options.input, InputSelection::Given(path.clone()))?;
+            }
+            "--example" => select_input(&mut options.input, InputSelection::Example)?,
+            value if days.is_none() && !value.starts_with("--") => {
+                days = Some(DaySelection::Single(parse_day(value)?));
+            }
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    let days = days.ok_or("Missing day, pass a day number or --all")?;
+    check_input(&days, &options.input)?;
+
+    if options.top.is_some() {
+        if days != DaySelection::Single(1) {
+            return Err("--top can only be used with day 1".to_string());
+        }
+
+        if options.format != OutputFormat::Text {
+            return Err("--top can only be used with --format text".to_string());
+        }
+    }
+
+    Ok(Command::Run { days, options })
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let mut it = args.iter();
+    let mut days = None;
+    let mut bench_args = BenchArgs::default();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--all" => days = Some(DaySelection::All),
+            "--iterations" => bench_args.options.iterations = parse_count(arg, it.next())?,
+            "--warmup" => bench_args.options.warmup = parse_count(arg, it.next())?,
+            "--format" => bench_args.format = parse_format(it.next())?,
+            "--input" => {
+                let path = it.next().ok_or("Missing value for --input")?;
+                select_input(&mut bench_args.input, InputSelection::Given(path.clone()))?;
+            }
+            "--example" => select_input(&mut bench_args.input, InputSelection::Example)?,
+            value if days.is_none() && !value.starts_with("--") => {
+                days = Some(DaySelection::Single(parse_day(value)?));
+            }
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    let days = days.ok_or("Missing day, pass a day number or --all")?;
+    check_input(&days, &bench_args.input)?;
+
+    if bench_args.options.iterations == 0 {
+        return Err("--iterations must be at least 1".to_string());
+    }
+
+    Ok(Command::Bench {
+        days,
+        args: bench_args,
+    })
+}
+
+fn parse_verify(args: &[String]) -> Result<Command, String> {
+    let days = match args {
+        [] => DaySelection::All,
+        [day] => DaySelection::Single(parse_day(day)?),
+        [_, unexpected, ..] => return Err(format!("Unexpected argument '{}'", unexpected)),
+    };
+
+    Ok(Command::Verify { days })
+}
+
+fn parse_new(args: &[String]) -> Result<Command, String> {
+    match args {
+        [day] => Ok(Command::New {
+            day: parse_day(day)?,
+        }),
+        [] => Err("Missing day for the new command".to_string()),
+        [_, unexpected, ..] => Err(format!("Unexpected argument '{}'", unexpected)),
+    }
+}
+
+fn parse_generate(args: &[String]) -> Result<Command, String> {
+    let mut it = args.iter();
+    let mut day = None;
+    let mut size = 1000;
+    let mut seed = 0;
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--size" => size = parse_count(arg, it.next())?,
+            "--seed" => seed = parse_seed(it.next())?,
+            value if day.is_none() && !value.starts_with("--") => day = Some(parse_day(value)?),
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    let day = day.ok_or("Missing day for the generate command")?;
+
+    Ok(Command::Generate { day, size, seed })
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(|arg| arg.as_str()) {
+        Some("run") => parse_run(&args[1..]),
+        Some("verify") => parse_verify(&args[1..]),
+        Some("bench") => parse_bench(&args[1..]),
+        Some("new") => parse_new(&args[1..]),
+        Some("generate") => parse_generate(&args[1..]),
+        Some(other) => Err(format!("Unknown command '{}'", other)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::input::to_args;
+
+    #[test]
+    fn parse_args_reads_single_day() {
+        let command = parse_args(&to_args("run 4")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::Single(4),
+                options: RunOptions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_part() {
+        let command = parse_args(&to_args("run 7 --part 2")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::Single(7),
+                options: RunOptions {
+                    part: Some(Part::Two),
+                    ..RunOptions::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_all_days() {
+        let command = parse_args(&to_args("run --all")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::All,
+                options: RunOptions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_input() {
+        let command = parse_args(&to_args("run 6 --input -")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::Single(6),
+                options: RunOptions {
+                    input: InputSelection::Given("-".to_string()),
+                    ..RunOptions::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_example() {
+        let command = parse_args(&to_args("run --all --example")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::All,
+                options: RunOptions {
+                    input: InputSelection::Example,
+                    ..RunOptions::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_format() {
+        let command = parse_args(&to_args("run --all --format csv")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::All,
+                options: RunOptions {
+                    format: OutputFormat::Csv,
+                    ..RunOptions::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_top() {
+        let command = parse_args(&to_args("run 1 --top 5")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: DaySelection::Single(1),
+                options: RunOptions {
+                    top: Some(5),
+                    ..RunOptions::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_verify() {
+        assert_eq!(
+            parse_args(&to_args("verify")).unwrap(),
+            Command::Verify {
+                days: DaySelection::All
+            }
+        );
+        assert_eq!(
+            parse_args(&to_args("verify 5")).unwrap(),
+            Command::Verify {
+                days: DaySelection::Single(5)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_bench() {
+        let command = parse_args(&to_args("bench 3 --iterations 50 --warmup 0 --format json")).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Bench {
+                days: DaySelection::Single(3),
+                args: BenchArgs {
+                    options: BenchOptions {
+                        iterations: 50,
+                        warmup: 0
+                    },
+                    format: OutputFormat::Json,
+                    ..BenchArgs::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_new() {
+        assert_eq!(parse_args(&to_args("new 9")).unwrap(), Command::New { day: 9 });
+    }
+
+    #[test]
+    fn parse_args_reads_generate() {
+        assert_eq!(
+            parse_args(&to_args("generate 3 --seed 42")).unwrap(),
+            Command::Generate {
+                day: 3,
+                size: 1000,
+                seed: 42
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_reads_full_width_seeds() {
+        assert_eq!(
+            parse_args(&to_args("generate 3 --seed 18446744073709551615")).unwrap(),
+            Command::Generate {
+                day: 3,
+                size: 1000,
+                seed: u64::MAX
+            }
+        );
+        assert_eq!(
+            parse_args(&to_args("generate 3 --seed -1")),
+            Err("Invalid seed '-1', expected a number from 0 to 18446744073709551615".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_invalid_input() {
+        assert!(parse_args(&to_args("")).is_err());
+        assert!(parse_args(&to_args("run")).is_err());
+        assert!(parse_args(&to_args("run x")).is_err());
+        assert!(parse_args(&to_args("run 1 --part 3")).is_err());
+        assert!(parse_args(&to_args("run 1 --input")).is_err());
+        assert!(parse_args(&to_args("run --all --input -")).is_err());
+        assert!(parse_args(&to_args("run 1 --input - --example")).is_err());
+        assert!(parse_args(&to_args("run 1 --format yaml")).is_err());
+        assert!(parse_args(&to_args("run 2 --top 3")).is_err());
+        assert!(parse_args(&to_args("run --all --top 3")).is_err());
+        assert!(parse_args(&to_args("run 1 --top 3 --format json")).is_err());
+        assert!(parse_args(&to_args("run 1 --top")).is_err());
+        assert!(parse_args(&to_args("verify 1 2")).is_err());
+        assert!(parse_args(&to_args("bench")).is_err());
+        assert!(parse_args(&to_args("bench 1 --iterations 0")).is_err());
+        assert!(parse_args(&to_args("bench 1 --warmup many")).is_err());
+        assert!(parse_args(&to_args("bench 1 --format xml")).is_err());
+        assert!(parse_args(&to_args("bench --all --input -")).is_err());
+        assert!(parse_args(&to_args("new")).is_err());
+        assert!(parse_args(&to_args("new 9 10")).is_err());
+        assert!(parse_args(&to_args("generate --size 10")).is_err());
+        assert!(parse_args(&to_args("generate 1 --seed")).is_err());
+        assert!(parse_args(&to_args("solve 1")).is_err());
+    }
+}
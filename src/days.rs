@@ -0,0 +1,18 @@
+use aoc_core::Puzzle;
+
+pub fn all() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &day01::Day01,
+        &day02::Day02,
+        &day03::Day03,
+        &day04::Day04,
+        &day05::Day05,
+        &day06::Day06,
+        &day07::Day07,
+        &day08::Day08,
+    ]
+}
+
+pub fn find(day: u32) -> Option<&'static dyn Puzzle> {
+    all().into_iter().find(|puzzle| puzzle.day() == day)
+}
@@ -1,3 +1,139 @@
-fn main() {
-    println!("Hello, world!");
+mod bench;
+mod cli;
+mod days;
+mod output;
+mod scaffold;
+mod verify;
+
+use aoc_core::input::InputSource;
+use aoc_core::{Part, Puzzle, Solution};
+use cli::{Command, DaySelection, InputSelection, OutputFormat, RunOptions};
+use output::Record;
+use std::env;
+use std::process::ExitCode;
+
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+fn read_input(puzzle: &dyn Puzzle, input: &InputSelection) -> Result<(String, String), String> {
+    let source = match input {
+        InputSelection::Example => {
+            let example = puzzle.example();
+            return Ok(("<example>".to_string(), example.input.to_string()));
+        }
+        InputSelection::Given(path) => InputSource::for_day(puzzle.day(), Some(path)),
+        InputSelection::Default => InputSource::for_day(puzzle.day(), None),
+    };
+
+    let input_data = source.read().map_err(|err| err.to_string())?;
+
+    Ok((source.to_string(), input_data))
+}
+
+fn run_puzzle(puzzle: &dyn Puzzle, options: &RunOptions, records: &mut Vec<Record>) -> Result<(), String> {
+    let day = puzzle.day();
+    let (source_name, input_data) = read_input(puzzle, &options.input)?;
+    let answers = puzzle
+        .solve(&input_data, &parts(options.part))
+        .map_err(|err| err.with_file(&source_name).render(&input_data))?;
+
+    for solved in answers {
+        let record = Record { day, solved };
+
+        if options.format == OutputFormat::Text {
+            println!("{}", record.to_text());
+        }
+
+        records.push(record);
+    }
+
+    if let Some(k) = options.top {
+        print_top_elves(&input_data, k)?;
+    }
+
+    Ok(())
+}
+
+fn print_top_elves(input_data: &str, k: usize) -> Result<(), String> {
+    let elves = day01::Day01::parse(input_data).map_err(|err| err.render(input_data))?;
+
+    for (rank, elf) in day01::top_k(&elves, k).iter().enumerate() {
+        println!(
+            "Day 01, top {}: elf {} carries {} calories",
+            rank + 1,
+            elf.index,
+            elf.total
+        );
+    }
+
+    Ok(())
+}
+
+fn run(selection: DaySelection, options: &RunOptions) -> Result<(), String> {
+    let puzzles = match selection {
+        DaySelection::Single(day) => {
+            vec![days::find(day).ok_or_else(|| format!("Day {} has not been solved yet", day))?]
+        }
+        DaySelection::All => days::all(),
+    };
+
+    let mut records = Vec::new();
+
+    for puzzle in puzzles {
+        run_puzzle(puzzle, options, &mut records)?;
+    }
+
+    match options.format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => println!("{}", output::to_json(&records)),
+        OutputFormat::Csv => {
+            println!("{}", output::CSV_HEADER);
+            records.iter().for_each(|record| println!("{}", record.to_csv()));
+        }
+    }
+
+    Ok(())
+}
+
+fn generate(day: u32, size: usize, seed: u64) -> Result<(), String> {
+    let puzzle = days::find(day).ok_or_else(|| format!("Day {} has not been solved yet", day))?;
+    let input_data = puzzle
+        .generate(seed, size)
+        .ok_or_else(|| format!("Day {} has no input generator", day))?;
+
+    print!("{}", input_data);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match cli::parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run { days, options } => run(days, &options),
+        Command::Verify { days } => verify::verify(days),
+        Command::Bench { days, args } => bench::bench(days, &args),
+        Command::New { day } => scaffold::new_day(day),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message.trim_end());
+            ExitCode::FAILURE
+        }
+    }
 }
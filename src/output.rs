@@ -0,0 +1,89 @@
+use aoc_core::json::{self, JsonObject};
+use aoc_core::{csv, Answer, Solved};
+
+pub const CSV_HEADER: &str = "day,part,answer,type,elapsed_ns";
+
+/// A single answer as emitted by `run --format json|csv`.
+#[derive(Debug)]
+pub struct Record {
+    pub day: u32,
+    pub solved: Solved,
+}
+
+impl Record {
+    pub fn to_text(&self) -> String {
+        format!(
+            "Day {:02}, part {}: {}",
+            self.day, self.solved.part, self.solved.answer
+        )
+    }
+
+    pub fn to_json(&self) -> JsonObject {
+        let record = JsonObject::new()
+            .number("day", self.day)
+            .number("part", self.solved.part);
+
+        let record = match &self.solved.answer {
+            Answer::Number(value) => record.number("answer", value),
+            Answer::Text(value) => record.string("answer", value),
+        };
+
+        record
+            .string("type", self.solved.answer.kind())
+            .number("elapsed_ns", self.solved.elapsed.as_nanos())
+    }
+
+    pub fn to_csv(&self) -> String {
+        csv::row([
+            self.day.to_string(),
+            self.solved.part.to_string(),
+            self.solved.answer.to_string(),
+            self.solved.answer.kind().to_string(),
+            self.solved.elapsed.as_nanos().to_string(),
+        ])
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    json::array(records.iter().map(|record| record.to_json().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+    use std::time::Duration;
+
+    fn record(answer: Answer) -> Record {
+        Record {
+            day: 5,
+            solved: Solved {
+                part: Part::Two,
+                answer,
+                elapsed: Duration::from_nanos(1500),
+            },
+        }
+    }
+
+    #[test]
+    fn records_render_as_text() {
+        assert_eq!(record(Answer::from("MCD")).to_text(), "Day 05, part 2: MCD");
+    }
+
+    #[test]
+    fn records_render_as_json() {
+        assert_eq!(
+            record(Answer::from("MCD")).to_json().to_string(),
+            "{\"day\":5,\"part\":2,\"answer\":\"MCD\",\"type\":\"text\",\"elapsed_ns\":1500}"
+        );
+        assert_eq!(
+            record(Answer::from(12)).to_json().to_string(),
+            "{\"day\":5,\"part\":2,\"answer\":12,\"type\":\"number\",\"elapsed_ns\":1500}"
+        );
+    }
+
+    #[test]
+    fn records_render_as_csv() {
+        assert_eq!(record(Answer::from("MCD")).to_csv(), "5,2,MCD,text,1500");
+    }
+}
@@ -0,0 +1,277 @@
+use aoc_core::input::{day_dir, workspace_dir};
+use std::fs;
+use std::path::Path;
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "{{name}}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+"#;
+
+const LIB_TEMPLATE: &str = r#"use aoc_core::{Answer, Example, ParseError, Solution};
+
+pub fn parse_input(input_data: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input_data.lines().map(|line| line.to_string()).collect())
+}
+
+pub struct {{type}};
+
+impl Solution for {{type}} {
+    const DAY: u32 = {{day}};
+    const EXAMPLE: Example = Example {
+        input: include_str!("../data/example.txt"),
+        part_one: "",
+        part_two: "",
+    };
+
+    type Input = Vec<String>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part_one(_input: &Self::Input) -> Answer {
+        "unsolved".into()
+    }
+
+    fn part_two(_input: &Self::Input) -> Answer {
+        "unsolved".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Remove the attribute once the example answers above are filled in.
+    aoc_core::example_tests!(#[ignore = "the example answers are not filled in yet"] super::{{type}});
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use aoc_core::{input, Solution};
+use {{name}}::{{type}};
+
+fn main() {
+    let input = input::parse_or_exit::<{{type}}>();
+
+    println!("Part one: {}", {{type}}::part_one(&input));
+    println!("Part two: {}", {{type}}::part_two(&input));
+}
+"#;
+
+fn render(template: &str, day: u32) -> String {
+    template
+        .replace("{{name}}", &format!("day{:02}", day))
+        .replace("{{type}}", &format!("Day{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `line` among the lines accepted by `is_entry`, which are expected
+/// to be kept in ascending order, or descending when `descending` is set.
+fn insert_sorted(
+    text: &str,
+    line: &str,
+    is_entry: impl Fn(&str) -> bool,
+    descending: bool,
+) -> Result<String, String> {
+    let mut lines: Vec<String> = text.lines().map(|line| line.to_string()).collect();
+    let key = line.trim().trim_end_matches(',');
+
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(&lines[i])).collect();
+    let last_entry = *entries
+        .last()
+        .ok_or(format!("No place found to register {}", key))?;
+
+    if entries
+        .iter()
+        .any(|&i| lines[i].trim().trim_end_matches(',') == key)
+    {
+        return Err(format!("{} is already registered", key));
+    }
+
+    let position = entries
+        .iter()
+        .find(|&&i| {
+            let existing = lines[i].trim();
+            if descending {
+                existing < key
+            } else {
+                existing > key
+            }
+        })
+        .copied()
+        .unwrap_or(last_entry + 1);
+
+    lines.insert(position, line.to_string());
+
+    // A new last element of a list without trailing comma takes over from
+    // the previous one.
+    if position == last_entry + 1 && !lines[last_entry].ends_with(',') && line.ends_with(',') {
+        lines[last_entry].push(',');
+        lines[position].pop();
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_member(manifest: &str, day: u32) -> Result<String, String> {
+    let member = format!("    \"day{:02}\",", day);
+    insert_sorted(
+        manifest,
+        &member,
+        |line| line.trim().starts_with("\"day"),
+        true,
+    )
+}
+
+fn register_dependency(manifest: &str, day: u32) -> Result<String, String> {
+    let dependency = format!("day{:02} = {{ path = \"day{:02}\" }}", day, day);
+    insert_sorted(manifest, &dependency, |line| line.starts_with("day"), false)
+}
+
+fn register_puzzle(registry: &str, day: u32) -> Result<String, String> {
+    let puzzle = format!("        &day{:02}::Day{:02},", day, day);
+    insert_sorted(
+        registry,
+        &puzzle,
+        |line| line.trim().starts_with("&day"),
+        false,
+    )
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+}
+
+fn update_file(path: &Path, update: impl Fn(&str) -> Result<String, String>) -> Result<(), String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+
+    write_file(path, &update(&text)?)
+}
+
+/// Creates the `dayNN` crate from the templates above and registers it in the
+/// workspace manifest, the runner's dependencies and the puzzle registry.
+pub fn new_day(day: u32) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!(
+            "Invalid day {}, expected a day between 1 and 25",
+            day
+        ));
+    }
+
+    let dir = day_dir(day);
+
+    if dir.exists() {
+        return Err(format!(
+            "{} already exists, refusing to overwrite it",
+            dir.display()
+        ));
+    }
+
+    let manifest_path = workspace_dir().join("Cargo.toml");
+    let registry_path = workspace_dir().join("src").join("days.rs");
+
+    // Check the registrations before touching the file system, so a failure
+    // leaves the workspace as it was.
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|err| format!("Unable to read {}: {}", manifest_path.display(), err))?;
+    register_dependency(&register_member(&manifest, day)?, day)?;
+
+    let registry = fs::read_to_string(&registry_path)
+        .map_err(|err| format!("Unable to read {}: {}", registry_path.display(), err))?;
+    register_puzzle(&registry, day)?;
+
+    let create_dir = |path: &Path| {
+        fs::create_dir_all(path)
+            .map_err(|err| format!("Unable to create {}: {}", path.display(), err))
+    };
+
+    create_dir(&dir.join("src"))?;
+    create_dir(&dir.join("data"))?;
+
+    write_file(&dir.join("Cargo.toml"), &render(CARGO_TEMPLATE, day))?;
+    write_file(&dir.join("src").join("lib.rs"), &render(LIB_TEMPLATE, day))?;
+    write_file(
+        &dir.join("src").join("main.rs"),
+        &render(MAIN_TEMPLATE, day),
+    )?;
+    write_file(&dir.join("data").join("input.txt"), "")?;
+    write_file(&dir.join("data").join("example.txt"), "")?;
+
+    update_file(&manifest_path, |text| {
+        register_dependency(&register_member(text, day)?, day)
+    })?;
+    update_file(&registry_path, |text| register_puzzle(text, day))?;
+
+    println!("Created {}", dir.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "adventofcode"
+
+[dependencies]
+aoc-core = { path = "aoc-core" }
+day01 = { path = "day01" }
+day03 = { path = "day03" }
+
+[workspace]
+members = [
+    "day03",
+    "day01",
+    "aoc-core"
+]
+"#;
+
+    #[test]
+    fn register_member_keeps_reverse_order() {
+        let manifest = register_member(MANIFEST, 2).unwrap();
+        let manifest = register_member(&manifest, 4).unwrap();
+
+        assert!(manifest.contains(
+            "members = [\n    \"day04\",\n    \"day03\",\n    \"day02\",\n    \"day01\",\n    \"aoc-core\"\n]"
+        ));
+    }
+
+    #[test]
+    fn register_dependency_keeps_order() {
+        let manifest = register_dependency(MANIFEST, 2).unwrap();
+
+        assert!(manifest.contains(
+            "day01 = { path = \"day01\" }\nday02 = { path = \"day02\" }\nday03 = { path = \"day03\" }\n"
+        ));
+    }
+
+    #[test]
+    fn register_puzzle_appends_new_days() {
+        let registry = "    vec![\n        &day01::Day01,\n    ]\n";
+
+        assert_eq!(
+            register_puzzle(registry, 12).unwrap(),
+            "    vec![\n        &day01::Day01,\n        &day12::Day12,\n    ]\n"
+        );
+    }
+
+    #[test]
+    fn registering_twice_fails() {
+        assert!(register_member(MANIFEST, 3).is_err());
+        assert!(register_dependency(MANIFEST, 1).is_err());
+    }
+
+    #[test]
+    fn render_fills_in_the_day() {
+        let main = render(MAIN_TEMPLATE, 9);
+
+        assert!(main.contains("use day09::Day09;"));
+        assert!(render(LIB_TEMPLATE, 9).contains("const DAY: u32 = 9;"));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::cli::DaySelection;
+use crate::days;
+use aoc_core::verify::{answers_path, ExpectedAnswers, Verdict};
+use aoc_core::{input::InputSource, Part, Puzzle, Solved};
+use std::fs;
+use std::io::ErrorKind;
+
+#[derive(Debug, Default)]
+struct Summary {
+    matched: usize,
+    mismatched: usize,
+    missing: usize,
+}
+
+fn load_expected_answers(day: u32) -> Result<ExpectedAnswers, String> {
+    let path = answers_path(day);
+
+    match fs::read_to_string(&path) {
+        Ok(text) => ExpectedAnswers::parse(&text)
+            .map_err(|err| err.with_file(&path.display().to_string()).render(&text)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(ExpectedAnswers::default()),
+        Err(err) => Err(format!("Unable to read {}: {}", path.display(), err)),
+    }
+}
+
+fn verify_puzzle(puzzle: &dyn Puzzle, summary: &mut Summary) -> Result<(), String> {
+    let day = puzzle.day();
+    let expected_answers = load_expected_answers(day)?;
+
+    let source = InputSource::for_day(day, None);
+    let input_data = source.read().map_err(|err| err.to_string())?;
+    let answers = puzzle
+        .solve(&input_data, &Part::ALL)
+        .map_err(|err| err.with_file(&source.to_string()).render(&input_data))?;
+
+    for Solved { part, answer, .. } in answers {
+        let verdict = match expected_answers.check(part, &answer) {
+            Verdict::Match => {
+                summary.matched += 1;
+                format!("match ({})", answer)
+            }
+            Verdict::Mismatch { expected } => {
+                summary.mismatched += 1;
+                format!("MISMATCH, expected {} but got {}", expected, answer)
+            }
+            Verdict::Missing => {
+                summary.missing += 1;
+                format!("missing, got {}", answer)
+            }
+        };
+
+        println!("Day {:02}, part {}: {}", day, part, verdict);
+    }
+
+    Ok(())
+}
+
+pub fn verify(selection: DaySelection) -> Result<(), String> {
+    let puzzles = match selection {
+        DaySelection::Single(day) => {
+            vec![days::find(day).ok_or_else(|| format!("Day {} has not been solved yet", day))?]
+        }
+        DaySelection::All => days::all(),
+    };
+
+    let mut summary = Summary::default();
+
+    for puzzle in puzzles {
+        verify_puzzle(puzzle, &mut summary)?;
+    }
+
+    println!(
+        "\n{} matched, {} mismatched, {} missing",
+        summary.matched, summary.mismatched, summary.missing
+    );
+
+    if summary.mismatched > 0 {
+        return Err(format!("{} answer(s) no longer match the recorded answers", summary.mismatched));
+    }
+
+    Ok(())
+}